@@ -0,0 +1,54 @@
+//Arithmetic on machine integers mod a modulus known only at run time, shared by the NTT and the
+//factorization code. These are const fns so that const generic moduli can be checked at compile
+//time.
+
+/// `a * b mod n`, through `u128` so that nothing overflows.
+pub(crate) const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+/// `a^e mod n`, by repeated squaring.
+pub(crate) const fn pow_mod(mut a: u64, mut e: u64, n: u64) -> u64 {
+    let mut res = 1 % n;
+    while e > 0 {
+        if !e.is_multiple_of(2) {
+            res = mul_mod(res, a, n);
+        }
+        a = mul_mod(a, a, n);
+        e /= 2;
+    }
+    res
+}
+/// Miller–Rabin with a set of bases that is known to be exact for all 64-bit integers.
+pub(crate) const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
@@ -0,0 +1,20 @@
+use std::{any::{Any, TypeId}, cell::RefCell, collections::HashMap};
+
+thread_local! {
+    static CACHE: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// The value of type `T` cached for the type `K` on this thread, computed by `f` the first time
+/// it is asked for. Types such as `Wrapper`s determine expensive derived data, like a Gröbner
+/// basis of the ideal they generate, that should only be computed once per type; keying by the
+/// type lets generic impls find it without any runtime state of their own.
+pub fn cached<K: 'static, T: Clone + 'static>(f: impl FnOnce() -> T) -> T {
+    let key = TypeId::of::<(K, T)>();
+    let hit = CACHE.with(|cache| cache.borrow().get(&key).map(|v| v.downcast_ref::<T>().unwrap().clone()));
+    hit.unwrap_or_else(|| {
+        // `f` may itself use the cache, so it must run while the cache is not borrowed.
+        let v = f();
+        CACHE.with(|cache| cache.borrow_mut().insert(key, Box::new(v.clone())));
+        v
+    })
+}
@@ -0,0 +1,42 @@
+use std::{error::Error, fmt::{self, Debug, Display}};
+
+use crate::{structure::{euclidean_ring::EuclideanRing, ring::RingOperations}, unit::TryInverse};
+
+/// Returned by [`crt`] when the modulus at `index` has a common factor `gcd`, which is not a
+/// unit, with the product of the moduli before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotCoprime<R> {
+    pub index: usize,
+    pub gcd: R,
+}
+impl<R: Debug> Display for NotCoprime<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "modulus {} shares the factor {:?} with the earlier moduli", self.index, self.gcd)
+    }
+}
+impl<R: Debug> Error for NotCoprime<R> {}
+
+/// The Chinese remainder theorem: given pairwise coprime moduli `m_i` and residues `a_i`, returns
+/// `(x, m)` where `m` is the product of the moduli and `x`, reduced mod `m`, is the unique class
+/// with `x = a_i` mod `m_i` for every `i`. No moduli give `(0, 1)`.
+///
+/// The congruences are combined one at a time: if `x = a` mod `m` and `s m + t m_i = g` is a unit,
+/// then `a + m s g^-1 (a_i - a)` solves both. The moduli must be nonzero. Panics if the slices
+/// have different lengths.
+pub fn crt<R: EuclideanRing<O>, O: RingOperations<R>>(residues: &[R], moduli: &[R]) -> Result<(R, R), NotCoprime<R>> {
+    assert_eq!(residues.len(), moduli.len(), "every residue needs a modulus");
+    let mut x = R::zero();
+    let mut m = R::one();
+    for (index, (a, n)) in residues.iter().zip(moduli).enumerate() {
+        let (s, t) = R::bézout(m.clone(), n.clone());
+        let g = s.clone().times(&m).plus(&t.times(n));
+        let g_inverse = match g.clone().try_inverse() {
+            Some(u) => u,
+            None => return Err(NotCoprime { index, gcd: g.normalized() }),
+        };
+        let step = m.clone().times(&s).times(&g_inverse).times(&a.clone().minus(&x));
+        m = m.times(n);
+        x = x.plus(&step).remainder(&m);
+    }
+    Ok((x, m))
+}
@@ -0,0 +1,53 @@
+use crate::{
+    cache::cached,
+    impls::StandardOps,
+    modular::Multiples,
+    polynomial::{PolyOps, Polynomial},
+    quotient::QuotientRing,
+    structure::ring::Ring,
+    wrapper::Wrapper,
+};
+
+type ZX = Polynomial<i64, StandardOps>;
+
+/// The `n`th cyclotomic polynomial `Φ_n`, the minimal polynomial over the rationals of a primitive
+/// `n`th root of unity. Since `x^n - 1` is the product of `Φ_d` over the divisors `d` of `n`, each
+/// `Φ_d` is found by dividing `x^d - 1` by the earlier ones; they are all monic, so the divisions
+/// are exact in `Z[x]`. Panics if `n` is zero.
+pub fn cyclotomic(n: usize) -> ZX {
+    assert!(n > 0, "there is no 0th cyclotomic polynomial");
+    let divisors: Vec<usize> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+    let mut found: Vec<(usize, ZX)> = Vec::with_capacity(divisors.len());
+    for &d in &divisors {
+        let mut phi = ZX::x_pow(d).minus(&ZX::one());
+        for (e, phi_e) in &found {
+            if d.is_multiple_of(*e) {
+                phi = Polynomial::divide(phi, phi_e).0;
+            }
+        }
+        found.push((d, phi));
+    }
+    found.pop().unwrap().1
+}
+
+/// `Φ_N` as a `Wrapper`, computed once per thread.
+#[derive(PartialEq, Eq, Clone)]
+pub struct CyclotomicWrap<const N: usize> {}
+impl<const N: usize> Wrapper<ZX> for CyclotomicWrap<N> {
+    const VAL: fn() -> ZX = || cached::<Self, _>(|| cyclotomic(N));
+}
+pub type CyclotomicMultiples<const N: usize> = Multiples<ZX, PolyOps<i64, StandardOps>, CyclotomicWrap<N>>;
+/// The cyclotomic integers `Z[x]/(Φ_N) = Z[ζ_N]`. Representatives are reduced mod `Φ_N`, so they
+/// have degree less than `φ(N)`.
+pub type CyclotomicRing<const N: usize> = QuotientRing<ZX, PolyOps<i64, StandardOps>, CyclotomicMultiples<N>>;
+/// The Gaussian integers `Z[i] = Z[x]/(x^2 + 1)`.
+pub type GaussianIntegers = CyclotomicRing<4>;
+/// The Eisenstein integers `Z[ω] = Z[x]/(x^2 + x + 1)`.
+pub type EisensteinIntegers = CyclotomicRing<3>;
+
+impl<const N: usize> CyclotomicRing<N> {
+    /// The class of `x`, a primitive `N`th root of unity.
+    pub fn zeta() -> Self {
+        Self::from(ZX::x())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    polynomial::{Degree, Polynomial},
+    power_series::{inverse_truncated, mul_truncated},
+    structure::ring::{Ring, RingOperations},
+    unit::TryInverse,
+};
+
+/// A divisor whose leading coefficient is a unit, with the first `precision` coefficients of
+/// `1 / rev(divisor)` computed ahead, where `rev(divisor) = x^n divisor(1/x)` for `n = deg divisor`.
+/// Reversing `f = q divisor + r` gives `rev(q) = rev(f) / rev(divisor) mod x^(deg q + 1)`, so each
+/// division is then one truncated product for the quotient and one for the remainder, instead of
+/// long division. The inverse only depends on the divisor, which pays off when one modulus divides
+/// many polynomials.
+pub struct PreparedDivisor<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    divisor: Polynomial<R, O>,
+    inverse: Vec<R>,
+}
+impl<R, O> PreparedDivisor<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + TryInverse<O>,
+{
+    /// Enough for quotients of degree below `precision`, such as that of a product of two
+    /// remainders when `precision = deg divisor`. The inverse is found by Newton iteration, in a
+    /// constant number of multiplications of size `precision`. Panics unless the leading
+    /// coefficient of `divisor` is a unit.
+    pub fn new(divisor: Polynomial<R, O>, precision: usize) -> Self {
+        let reversed: Vec<R> = divisor.coefficients.iter().rev().cloned().collect();
+        let mut inverse = inverse_truncated(&reversed, precision).expect("the leading coefficient must be a unit");
+        inverse.resize(precision, R::zero());
+        PreparedDivisor { divisor, inverse }
+    }
+    pub fn divisor(&self) -> &Polynomial<R, O> {
+        &self.divisor
+    }
+    pub fn precision(&self) -> usize {
+        self.inverse.len()
+    }
+    /// `(q, r)` with `dividend = q divisor + r` and `deg r < deg divisor`. A quotient too long for
+    /// the precision is found with a fresh inverse of the precision it needs.
+    pub fn divide(&self, dividend: Polynomial<R, O>) -> (Polynomial<R, O>, Polynomial<R, O>) {
+        let n = self.divisor.degree().unwrap();
+        let m = match dividend.degree() {
+            Degree::Integer(m) if m >= n => m,
+            _ => return (Polynomial::zero(), dividend),
+        };
+        let k = m - n + 1;
+        if k > self.precision() {
+            return Self::new(self.divisor.clone(), k).divide(dividend);
+        }
+        let reversed: Vec<R> = dividend.coefficients[n..].iter().rev().cloned().collect();
+        let mut q = mul_truncated(&reversed, &self.inverse, k);
+        q.resize(k, R::zero());
+        q.reverse();
+        let q = Polynomial::from_coefficients(q);
+        let r = dividend.minus(&q.clone().times(&self.divisor));
+        (q, r)
+    }
+    pub fn remainder(&self, dividend: Polynomial<R, O>) -> Polynomial<R, O> {
+        self.divide(dividend).1
+    }
+}
+impl<R, O> Clone for PreparedDivisor<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn clone(&self) -> Self {
+        PreparedDivisor { divisor: self.divisor.clone(), inverse: self.inverse.clone() }
+    }
+}
@@ -0,0 +1,454 @@
+use std::marker::PhantomData;
+
+use crate::{
+    arithmetic::{is_prime, mul_mod},
+    impls::StandardOps,
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::{Degree, Polynomial},
+    quotient::{PrimeModulus, Residues},
+    structure::{
+        euclidean_ring::EuclideanRing,
+        field::Field,
+        group::Group,
+        ring::{Ring, RingOperations},
+    },
+};
+
+/// A leading coefficient or content, together with irreducible factors and their multiplicities.
+pub type Factorization<R, O> = (R, Vec<(Polynomial<R, O>, usize)>);
+
+/// A xorshift generator. Randomized splitting only needs a cheap source of varied polynomials,
+/// and a fixed seed keeps factorizations reproducible.
+struct XorShift(u64);
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+impl<F, O> Polynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// `self^e mod modulus`, by repeated squaring. Panics if the modulus is zero.
+    //This cannot be `Monoid::pow` in `QuotientRing<Self, _, Multiples<..>>`: the modulus is only
+    //known at run time, while `Multiples` takes it from a `Wrapper`, whose value is fixed by its
+    //type. So the square-and-multiply loop is written out, reducing with `remainder`.
+    pub fn pow_mod(&self, mut e: u64, modulus: &Self) -> Self {
+        let mut base = self.clone().remainder(modulus);
+        let mut res = Self::one().remainder(modulus);
+        while e > 0 {
+            if e % 2 == 1 {
+                res = (res * base.clone()).remainder(modulus);
+            }
+            base = (base.clone() * base).remainder(modulus);
+            e /= 2;
+        }
+        res
+    }
+    /// Yun's square-free decomposition: monic, square-free, pairwise coprime `g_i` such that
+    /// `self` is its leading coefficient times the product of the `g_i^i`. Only the pairs with
+    /// `g_i != 1` are returned, in increasing order of multiplicity.
+    ///
+    /// This is only correct when the characteristic is zero or larger than the degree; over
+    /// `IntegersMod<P>` use [`Polynomial::square_free_decomposition`]. Panics on zero.
+    pub fn yun_square_free(&self) -> Vec<(Self, usize)> {
+        assert!(self.degree() != Degree::NegInfty, "zero has no square-free decomposition");
+        let f = self.clone().normalized();
+        let df = f.derivative();
+        let a = Self::gcd(f.clone(), df.clone()).normalized();
+        let mut b = f.quotient(&a);
+        let mut d = df.quotient(&a).minus(&b.derivative());
+        let mut res = vec![];
+        let mut i = 1;
+        while b.degree() > 0 {
+            let a = Self::gcd(b.clone(), d.clone()).normalized();
+            b = b.quotient(&a);
+            d = d.quotient(&a).minus(&b.derivative());
+            if a.degree() > 0 {
+                res.push((a, i));
+            }
+            i += 1;
+        }
+        res
+    }
+}
+
+//Over the integers mod any prime wrapper A, such as IntegersMod<P>; below P is the prime A::VAL().
+impl<A: PrimeModulus> Polynomial<Residues<A>, StandardOps> {
+    /// The polynomial `g` with `g^P == self`. Over the prime field, this just keeps every
+    /// `P`-th coefficient. Panics unless `self` only has terms in powers of `x^P`.
+    pub fn pth_root(&self) -> Self {
+        let p = A::VAL();
+        let mut coefficients = vec![];
+        for (i, c) in self.coefficients.iter().enumerate() {
+            if i as i64 % p == 0 {
+                coefficients.push(c.clone());
+            } else {
+                assert!(c.residue() == 0, "not a P-th power");
+            }
+        }
+        Polynomial { coefficients, o: PhantomData }
+    }
+    /// The square-free decomposition over the prime field `IntegersMod<P>`: monic, square-free,
+    /// pairwise coprime factors with their multiplicities, such that `self` is its leading
+    /// coefficient times the product of the `factor^multiplicity`. Unlike
+    /// [`Polynomial::yun_square_free`], this handles factors whose multiplicity is a multiple of
+    /// `P`, which show up as a zero derivative, by taking `P`-th roots. Panics on zero.
+    pub fn square_free_decomposition(&self) -> Vec<(Self, usize)> {
+        assert!(self.degree() != Degree::NegInfty, "zero has no square-free decomposition");
+        let f = self.clone().normalized();
+        let mut res = vec![];
+        let mut c = f.clone();
+        let df = f.derivative();
+        if df.degree() != Degree::NegInfty {
+            c = Self::gcd(f.clone(), df).normalized();
+            let mut w = f.quotient(&c);
+            let mut i = 1;
+            while w.degree() > 0 {
+                let y = Self::gcd(w.clone(), c.clone()).normalized();
+                let z = w.quotient(&y);
+                if z.degree() > 0 {
+                    res.push((z, i));
+                }
+                i += 1;
+                w = y.clone();
+                c = c.quotient(&y);
+            }
+        }
+        if c.degree() > 0 {
+            for (g, m) in c.pth_root().square_free_decomposition() {
+                res.push((g, m * A::VAL() as usize));
+            }
+        }
+        res.sort_by_key(|(_, m)| *m);
+        res
+    }
+}
+
+impl<A: PrimeModulus> Polynomial<Residues<A>, StandardOps> {
+    /// Distinct-degree factorization of a monic square-free polynomial: pairs `(g, d)` where `g`
+    /// is the product of all the irreducible factors of degree `d`. Uses that `x^(P^d) - x` is the
+    /// product of all monic irreducibles whose degree divides `d`.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let mut res = vec![];
+        let mut f = self.clone();
+        let mut h = Self::x().remainder(&f);
+        let mut d = 0;
+        while f.degree() >= 2 * (d + 1) {
+            d += 1;
+            h = h.pow_mod(A::VAL() as u64, &f);
+            let g = Self::gcd(f.clone(), h.clone().minus(&Self::x())).normalized();
+            if g.degree() > 0 {
+                f = f.quotient(&g);
+                h = h.remainder(&f);
+                res.push((g, d));
+            }
+        }
+        if f.degree() > 0 {
+            let d = f.degree().unwrap();
+            res.push((f, d));
+        }
+        res
+    }
+    /// Cantor–Zassenhaus splitting of a monic square-free polynomial all of whose irreducible
+    /// factors have degree `d` into those factors. For odd `P`, `gcd(a^((P^d-1)/2) - 1, self)`
+    /// for random `a` is a proper factor about half the time; for `P = 2` the trace
+    /// `a + a^2 + ... + a^(2^(d-1))` takes the place of the power.
+    pub fn equal_degree_factorization(&self, d: usize) -> Vec<Self> {
+        self.equal_degree_split(d, &mut XorShift(0x2545F4914F6CDD1D))
+    }
+    fn equal_degree_split(&self, d: usize, rng: &mut XorShift) -> Vec<Self> {
+        let n = self.degree().unwrap();
+        if n <= d {
+            return vec![self.clone()];
+        }
+        let p = A::VAL() as u64;
+        loop {
+            let a = Self::from_coefficients((0..n).map(|_| Residues::from((rng.next() % p) as i64)).collect());
+            if a.degree() < 1 {
+                continue;
+            }
+            // Accumulate a^(1+P+...+P^(d-1)) (or the trace when P = 2) by repeated Frobenius.
+            let mut power = a.clone();
+            let mut acc = a;
+            for _ in 1..d {
+                power = power.pow_mod(p, self);
+                acc = if p == 2 {
+                    acc.plus(&power)
+                } else {
+                    (acc * power.clone()).remainder(self)
+                };
+            }
+            let b = if p == 2 {
+                acc
+            } else {
+                acc.pow_mod((p - 1) / 2, self).minus(&Self::one())
+            };
+            let g = Self::gcd(self.clone(), b).normalized();
+            if g.degree() > 0 && g.degree() < n {
+                let mut res = g.equal_degree_split(d, rng);
+                res.extend(self.clone().quotient(&g).equal_degree_split(d, rng));
+                return res;
+            }
+        }
+    }
+    /// The monic irreducible factors of a monic square-free polynomial, by distinct-degree
+    /// factorization followed by Cantor–Zassenhaus.
+    pub fn cantor_zassenhaus(&self) -> Vec<Self> {
+        let mut res = vec![];
+        for (g, d) in self.distinct_degree_factorization() {
+            res.extend(g.equal_degree_factorization(d));
+        }
+        res
+    }
+    /// The monic irreducible factors of a monic square-free polynomial, by Berlekamp's algorithm.
+    /// The number of factors is the dimension of the space of `g` with `g^P = g mod self`, and
+    /// each such `g` splits `self` as the product of the `gcd(self, g - s)` over `s` in `0..P`.
+    /// That loop over the field makes this only practical for small `P`.
+    pub fn berlekamp(&self) -> Vec<Self> {
+        let n = self.degree().unwrap();
+        let p = A::VAL();
+        // Row i of q holds x^(iP) mod self; we want the left kernel of q - 1.
+        let xp = Self::x().pow_mod(p as u64, self);
+        let mut row = Self::one();
+        let mut q = vec![];
+        for _ in 0..n {
+            q.push((0..n).map(|j| row.coefficient(j).residue()).collect::<Vec<_>>());
+            row = (row * xp.clone()).remainder(self);
+        }
+        let a = (0..n)
+            .map(|j| (0..n).map(|i| if i == j { (q[i][j] - 1).rem_euclid(p) } else { q[i][j] }).collect())
+            .collect();
+        let basis = null_space::<A>(a);
+        let mut factors = vec![self.clone()];
+        for v in basis.iter().skip(1) {
+            if factors.len() == basis.len() {
+                break;
+            }
+            let v = Self::from_coefficients(v.iter().map(|c| Residues::from(*c)).collect());
+            let mut next = vec![];
+            for mut h in factors {
+                for s in 0..p {
+                    if h.degree() <= 1 {
+                        break;
+                    }
+                    let g = Self::gcd(h.clone(), v.clone().minus(&Self::constant(Residues::from(s))))
+                        .normalized();
+                    if g.degree() > 0 && g.degree() < h.degree() {
+                        h = h.quotient(&g);
+                        next.push(g);
+                    }
+                }
+                next.push(h);
+            }
+            factors = next;
+        }
+        factors
+    }
+    /// The factorization into its leading coefficient and monic irreducible factors with their
+    /// multiplicities, sorted by degree. Panics on zero.
+    pub fn factor(&self) -> Factorization<Residues<A>, StandardOps> {
+        self.factor_with(Self::cantor_zassenhaus)
+    }
+    /// Like [`Polynomial::factor`], but splitting the square-free parts by Berlekamp's algorithm.
+    pub fn factor_berlekamp(&self) -> Factorization<Residues<A>, StandardOps> {
+        self.factor_with(Self::berlekamp)
+    }
+    fn factor_with(&self, split: fn(&Self) -> Vec<Self>) -> Factorization<Residues<A>, StandardOps> {
+        let mut res = vec![];
+        for (g, m) in self.square_free_decomposition() {
+            for h in split(&g) {
+                res.push((h, m));
+            }
+        }
+        res.sort_by_key(|(h, m)| (h.coefficients.len(), h.coefficients.iter().map(Residues::residue).collect::<Vec<_>>(), *m));
+        (self.lead_coeff(), res)
+    }
+}
+/// A basis of the null space of a matrix over the integers mod the prime `p = A::VAL()`, by
+/// reduction to row echelon form. Entries are residues in `0..p`.
+fn null_space<A: PrimeModulus>(mut a: Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+    let p = A::VAL();
+    let n = a.first().map_or(0, Vec::len);
+    let mul = |x: i64, y: i64| (x as i128 * y as i128).rem_euclid(p as i128) as i64;
+    let mut pivots = vec![];
+    let mut r = 0;
+    for c in 0..n {
+        let Some(k) = (r..a.len()).find(|&k| a[k][c] != 0) else {
+            continue;
+        };
+        a.swap(r, k);
+        let inverse = Residues::<A>::from(a[r][c]).reciprocal().residue();
+        for x in a[r].iter_mut() {
+            *x = mul(*x, inverse);
+        }
+        let pivot = a[r].clone();
+        for (k, row) in a.iter_mut().enumerate() {
+            if k != r && row[c] != 0 {
+                let factor = row[c];
+                for (x, y) in row.iter_mut().zip(&pivot) {
+                    *x = (*x - mul(factor, *y)).rem_euclid(p);
+                }
+            }
+        }
+        pivots.push(c);
+        r += 1;
+    }
+    let mut basis = vec![];
+    for free in (0..n).filter(|c| !pivots.contains(c)) {
+        let mut v = vec![0; n];
+        v[free] = 1;
+        for (i, &c) in pivots.iter().enumerate() {
+            v[c] = (-a[i][free]).rem_euclid(p);
+        }
+        basis.push(v);
+    }
+    basis
+}
+
+/// A nontrivial factor of the odd composite `n`, by Pollard's rho method.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| (mul_mod(x, x, n) + c) % n;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd_u64(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+fn gcd_u64(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd_u64(b, a % b)
+    }
+}
+/// The distinct prime factors of `n`, in increasing order.
+fn prime_factors_u64(n: u64) -> Vec<u64> {
+    fn collect(n: u64, res: &mut Vec<u64>) {
+        if n == 1 {
+        } else if n.is_multiple_of(2) {
+            res.push(2);
+            collect(n >> n.trailing_zeros(), res);
+        } else if is_prime(n) {
+            res.push(n);
+        } else {
+            let d = pollard_rho(n);
+            collect(d, res);
+            collect(n / d, res);
+        }
+    }
+    let mut res = vec![];
+    collect(n, &mut res);
+    res.sort();
+    res.dedup();
+    res
+}
+
+impl<A: PrimeModulus> Polynomial<Residues<A>, StandardOps> {
+    /// `x^(P^k) mod self`, by `k` applications of the Frobenius map.
+    fn frobenius_power_of_x(&self, k: usize) -> Self {
+        let mut h = Self::x().remainder(self);
+        for _ in 0..k {
+            h = h.pow_mod(A::VAL() as u64, self);
+        }
+        h
+    }
+    /// Rabin's irreducibility test: a polynomial `f` of degree `n > 0` is irreducible over
+    /// `IntegersMod<P>` exactly when `x^(P^n) = x mod f`, and `x^(P^(n/q)) - x` is coprime to
+    /// `f` for every prime `q` dividing `n`.
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            Degree::Integer(n) if n > 0 => n,
+            _ => return false,
+        };
+        let f = self.clone().normalized();
+        let x = Self::x().remainder(&f);
+        prime_factors_u64(n as u64).into_iter().all(|q| {
+            let h = f.frobenius_power_of_x(n / q as usize).minus(&x);
+            Self::gcd(f.clone(), h).degree() == 0
+        }) && f.frobenius_power_of_x(n) == x
+    }
+    /// Whether `self` is a primitive polynomial: irreducible, and such that `x` generates the
+    /// multiplicative group of `IntegersMod<P>[x]/(self)`, i.e. has order `P^n - 1`. These are
+    /// the feedback polynomials of maximal-length LFSRs. Panics if `P^n - 1` does not fit in a
+    /// `u64`, since it has to be factored.
+    pub fn is_primitive(&self) -> bool {
+        if !self.is_irreducible() || self.coefficient(0).residue() == 0 {
+            return false;
+        }
+        let n = self.degree().unwrap() as u32;
+        let order = (A::VAL() as u64)
+            .checked_pow(n)
+            .expect("P^n does not fit in a u64")
+            - 1;
+        let f = self.clone().normalized();
+        prime_factors_u64(order)
+            .into_iter()
+            .all(|q| Self::x().pow_mod(order / q, &f) != Self::one())
+    }
+    /// All monic polynomials of degree `n`, in lexicographic order of their coefficients from the
+    /// constant term up.
+    pub fn monic_polynomials(n: usize) -> impl Iterator<Item = Self> {
+        let p = A::VAL();
+        std::iter::successors(Some(vec![0; n]), move |digits| {
+            let mut digits = digits.clone();
+            let i = digits.iter().position(|&d| d < p - 1)?;
+            digits[i] += 1;
+            digits[..i].iter_mut().for_each(|d| *d = 0);
+            Some(digits)
+        })
+        .map(|digits| {
+            let mut coefficients: Vec<Residues<A>> = digits.into_iter().map(Residues::from).collect();
+            coefficients.push(Residues::from(1));
+            Polynomial { coefficients, o: PhantomData }
+        })
+    }
+    /// All monic irreducible polynomials of degree `n`, in the order of
+    /// [`Polynomial::monic_polynomials`].
+    pub fn irreducible_polynomials(n: usize) -> impl Iterator<Item = Self> {
+        Self::monic_polynomials(n).filter(Self::is_irreducible)
+    }
+    /// All primitive polynomials of degree `n`, in the order of
+    /// [`Polynomial::monic_polynomials`].
+    pub fn primitive_polynomials(n: usize) -> impl Iterator<Item = Self> {
+        Self::monic_polynomials(n).filter(Self::is_primitive)
+    }
+    /// A monic irreducible polynomial of degree `n > 0`, found by testing random monic
+    /// polynomials. About one in `n` of them is irreducible. The same seed gives the same result.
+    pub fn random_irreducible(n: usize, seed: u64) -> Self {
+        Self::random_search(n, seed, Self::is_irreducible)
+    }
+    /// A primitive polynomial of degree `n > 0`, found by testing random monic polynomials.
+    pub fn random_primitive(n: usize, seed: u64) -> Self {
+        Self::random_search(n, seed, Self::is_primitive)
+    }
+    fn random_search(n: usize, seed: u64, test: fn(&Self) -> bool) -> Self {
+        assert!(n > 0, "there are no irreducible polynomials of degree 0");
+        let mut rng = XorShift(seed | 1);
+        let p = A::VAL() as u64;
+        loop {
+            let mut coefficients: Vec<Residues<A>> =
+                (0..n).map(|_| Residues::from((rng.next() % p) as i64)).collect();
+            coefficients.push(Residues::from(1));
+            let f = Polynomial { coefficients, o: PhantomData };
+            if test(&f) {
+                return f;
+            }
+        }
+    }
+}
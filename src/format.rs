@@ -0,0 +1,185 @@
+use std::{error::Error, fmt::{self, Debug, Display}, str::FromStr};
+
+use crate::{polynomial::Polynomial, structure::ring::{Ring, RingOperations}};
+
+/// Displays a polynomial in the variable `var`, like `3x^2 - x + 1`. Made by
+/// [`Polynomial::display`]; the `Display` impl of `Polynomial` itself uses `x`.
+pub struct PolynomialDisplay<'a, R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    polynomial: &'a Polynomial<R, O>,
+    var: &'a str,
+}
+impl<R, O> Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    pub fn display<'a>(&'a self, var: &'a str) -> PolynomialDisplay<'a, R, O> {
+        PolynomialDisplay { polynomial: self, var }
+    }
+    /// Writes the nonzero terms from the highest degree down. A coefficient whose own rendering
+    /// has several terms, such as a polynomial, is put in parentheses; a leading `-` on any
+    /// other coefficient becomes the sign of its term, and coefficients `1` are left out.
+    fn render(&self, f: &mut fmt::Formatter<'_>, var: &str, coefficient: impl Fn(&R) -> String) -> fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (n, c) in self.coefficients.iter().enumerate().rev() {
+            if c == &R::zero() {
+                continue;
+            }
+            let mut c = coefficient(c);
+            let compound = c.char_indices().any(|(i, ch)| ch == ' ' || (i > 0 && (ch == '+' || ch == '-')));
+            let negative = !compound && c.starts_with('-');
+            if compound {
+                c = format!("({c})");
+            } else if negative {
+                c.remove(0);
+            }
+            if n > 0 && c == "1" {
+                c.clear();
+            }
+            let sign = match (first, negative) {
+                (true, false) => "",
+                (true, true) => "-",
+                (false, false) => " + ",
+                (false, true) => " - ",
+            };
+            match n {
+                0 => write!(f, "{sign}{c}")?,
+                1 => write!(f, "{sign}{c}{var}")?,
+                _ => write!(f, "{sign}{c}{var}^{n}")?,
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+impl<R, O> Display for PolynomialDisplay<'_, R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.polynomial.render(f, self.var, |c| c.to_string())
+    }
+}
+impl<R, O> Display for Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, "x", |c| c.to_string())
+    }
+}
+impl<R, O> Debug for Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, "x", |c| format!("{c:?}"))
+    }
+}
+
+/// The part of the input that could not be read as a term of a polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePolynomialError {
+    pub term: String,
+}
+impl Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse `{}` as a term of a polynomial", self.term)
+    }
+}
+impl Error for ParsePolynomialError {}
+
+/// The nesting depth of brackets just after each byte of `s`, so that operators inside a
+/// parenthesized coefficient are not mistaken for ones between terms.
+fn depths(s: &str) -> Vec<i32> {
+    let mut depth = 0;
+    s.bytes()
+        .map(|b| {
+            match b {
+                b'(' | b'[' => depth += 1,
+                b')' | b']' => depth -= 1,
+                _ => {}
+            }
+            depth
+        })
+        .collect()
+}
+impl<R, O> Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + FromStr,
+{
+    /// Parses the syntax that `Display` produces, in the variable `var`: a sum of terms
+    /// `c var^n`, with `c` parsed by `R` and optionally in parentheses or followed by `*`, `c`
+    /// omitted for `1`, and `^n` omitted for `n = 1`. Whitespace is ignored, terms may come in any
+    /// order, and repeated powers are added together.
+    pub fn parse_with(s: &str, var: &str) -> Result<Self, ParsePolynomialError> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let depth = depths(&s);
+        let mut terms = vec![];
+        let mut start = 0;
+        for (i, b) in s.bytes().enumerate() {
+            let after_operator = i > 0 && matches!(s.as_bytes()[i - 1], b'^' | b'*' | b'+' | b'-');
+            if (b == b'+' || b == b'-') && i > 0 && depth[i] == 0 && !after_operator {
+                terms.push(&s[start..i]);
+                start = i;
+            }
+        }
+        terms.push(&s[start..]);
+        let mut coefficients = vec![];
+        for term in terms {
+            let error = || ParsePolynomialError { term: term.to_string() };
+            let (negative, body) = match term.as_bytes().first() {
+                Some(b'-') => (true, &term[1..]),
+                Some(b'+') => (false, &term[1..]),
+                _ => (false, term),
+            };
+            let depth = depths(body);
+            let var_at = body.rmatch_indices(var).map(|(i, _)| i).find(|&i| i == 0 || depth[i - 1] == 0);
+            let (c, n) = match var_at {
+                Some(i) if body[i + var.len()..].is_empty() => (&body[..i], 1),
+                Some(i) => match body[i + var.len()..].strip_prefix('^') {
+                    Some(n) => (&body[..i], n.parse::<usize>().map_err(|_| error())?),
+                    None => (body, 0),
+                },
+                None => (body, 0),
+            };
+            if c.is_empty() && n == 0 {
+                return Err(error());
+            }
+            let c = c.strip_suffix('*').unwrap_or(c);
+            let c = match c.strip_prefix('(').and_then(|c| c.strip_suffix(')')) {
+                Some(inner) => inner,
+                None => c,
+            };
+            let c = if c.is_empty() { R::one() } else { c.parse::<R>().map_err(|_| error())? };
+            if coefficients.len() <= n {
+                coefficients.resize(n + 1, R::zero());
+            }
+            let c = if negative { c.negated() } else { c };
+            coefficients[n] = coefficients[n].clone().plus(&c);
+        }
+        Ok(Self::from_coefficients(coefficients))
+    }
+}
+impl<R, O> FromStr for Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + FromStr,
+{
+    type Err = ParsePolynomialError;
+    /// Parses a polynomial in `x`. See [`Polynomial::parse_with`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, "x")
+    }
+}
@@ -0,0 +1,310 @@
+use std::marker::PhantomData;
+use crate::{
+    cache::cached,
+    multivariate::{Monomial, MonomialOrder, MultiOps, MultiPolynomial, MPlus, MTimes},
+    nonzero::NonZero,
+    operation::O2,
+    set::{Set, Subset},
+    structure::{field::Field, group::{Group, Subgroup}, monoid::{AbsorbingSubset, Monoid}, ring::{Ideal, Ring, RingOperations}},
+    wrapper::Wrapper,
+};
+
+impl<F, O, const N: usize, M> MultiPolynomial<F, O, N, M>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder,
+{
+    /// `self` divided by its leading coefficient. Zero stays zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_term() {
+            Some((_, c)) => self.mul_term(&c.reciprocal(), &Monomial::one()),
+            None => self.clone(),
+        }
+    }
+    /// The remainder of `self` on multivariate division by `divisors`: no term of it is divisible
+    /// by the leading monomial of any divisor. When `divisors` is a Gröbner basis this is the
+    /// normal form, which is zero exactly when `self` lies in the ideal they generate.
+    pub fn normal_form(&self, divisors: &[Self]) -> Self {
+        let mut p = self.clone();
+        let mut r = Self::zero();
+        while let Some((m, c)) = p.leading_term() {
+            let divisor = divisors.iter().find_map(|g| {
+                let (n, d) = g.leading_term()?;
+                Some((g, m.quotient(&n)?, d))
+            });
+            match divisor {
+                Some((g, q, d)) => p = p.minus(&g.mul_term(&c.times(&d.reciprocal()), &q)),
+                None => {
+                    p.terms.remove(&m);
+                    r.terms.insert(m, c);
+                }
+            }
+        }
+        r
+    }
+    /// The S-polynomial of `f` and `g`, which cancels their leading terms.
+    pub fn s_polynomial(f: &Self, g: &Self) -> Self {
+        let (m, c) = f.leading_term().unwrap();
+        let (n, d) = g.leading_term().unwrap();
+        let l = m.lcm(&n);
+        f.mul_term(&c.reciprocal(), &l.quotient(&m).unwrap())
+            .minus(&g.mul_term(&d.reciprocal(), &l.quotient(&n).unwrap()))
+    }
+}
+
+/// A critical pair of basis elements, by their indices, and the lcm of their leading monomials.
+struct Pair<const N: usize, M: MonomialOrder> {
+    i: usize,
+    j: usize,
+    lcm: Monomial<N, M>,
+}
+
+/// The reduced Gröbner basis of the ideal generated by `generators` with respect to `M`: monic,
+/// with no term of any element divisible by the leading monomial of another, sorted by leading
+/// monomial. It is determined by the ideal and the order, so two sets of generators give the
+/// same ideal exactly when their reduced bases are equal. The zero ideal has the empty basis.
+///
+/// This is Buchberger's algorithm with the normal selection strategy (the pair with the least
+/// lcm goes first), where the Gebauer–Möller criteria discard most pairs that would reduce to
+/// zero before their S-polynomials are formed. Faugère's F4, which reduces many pairs at once by
+/// linear algebra, is not implemented.
+pub fn groebner_basis<F, O, const N: usize, M>(generators: &[MultiPolynomial<F, O, N, M>]) -> Vec<MultiPolynomial<F, O, N, M>>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder,
+{
+    let mut polys: Vec<MultiPolynomial<F, O, N, M>> = vec![];
+    let mut basis: Vec<usize> = vec![];
+    let mut pairs = vec![];
+    for f in generators {
+        let current: Vec<_> = basis.iter().map(|&i| polys[i].clone()).collect();
+        let h = f.normal_form(&current);
+        if !h.is_zero() {
+            update(&mut polys, &mut basis, &mut pairs, h.monic());
+        }
+    }
+    while let Some(k) = (0..pairs.len()).min_by_key(|&k| pairs[k].lcm) {
+        let Pair { i, j, .. } = pairs.swap_remove(k);
+        let current: Vec<_> = basis.iter().map(|&i| polys[i].clone()).collect();
+        let h = MultiPolynomial::s_polynomial(&polys[i], &polys[j]).normal_form(&current);
+        if !h.is_zero() {
+            update(&mut polys, &mut basis, &mut pairs, h.monic());
+        }
+    }
+    // The basis is minimal, so reducing each element by the others keeps its leading monomial.
+    let minimal: Vec<_> = basis.iter().map(|&i| polys[i].clone()).collect();
+    let mut reduced: Vec<_> = (0..minimal.len())
+        .map(|k| {
+            let others: Vec<_> = minimal.iter().enumerate().filter(|(l, _)| *l != k).map(|(_, g)| g.clone()).collect();
+            minimal[k].normal_form(&others)
+        })
+        .collect();
+    reduced.sort_by_key(|g| g.leading_monomial());
+    reduced
+}
+
+/// Adds the monic polynomial `h`, already reduced by the basis, to the basis and to the list of
+/// critical pairs, following Gebauer and Möller. A new pair `(g, h)` is dropped if the leading
+/// monomials of `g` and `h` are coprime (Buchberger's first criterion), or if its lcm is a
+/// multiple of the lcm of another new pair (the chain criterion). An old pair `(f, g)` is
+/// dropped if `lm(h)` divides its lcm strictly, in the sense that it differs from the lcms of
+/// both `(f, h)` and `(g, h)`. Basis elements whose leading monomial is a multiple of `lm(h)`
+/// leave the basis, though pairs that already involve them are kept.
+fn update<F, O, const N: usize, M>(
+    polys: &mut Vec<MultiPolynomial<F, O, N, M>>,
+    basis: &mut Vec<usize>,
+    pairs: &mut Vec<Pair<N, M>>,
+    h: MultiPolynomial<F, O, N, M>,
+) where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder,
+{
+    let lm = |k: usize| polys[k].leading_monomial().unwrap();
+    let t = polys.len();
+    let ht = h.leading_monomial().unwrap();
+    let candidates: Vec<Pair<N, M>> = basis.iter().map(|&i| Pair { i, j: t, lcm: lm(i).lcm(&ht) }).collect();
+    let mut kept: Vec<&Pair<N, M>> = vec![];
+    for (k, p) in candidates.iter().enumerate() {
+        let coprime = lm(p.i).is_coprime(&ht);
+        let chained = candidates[k + 1..].iter().chain(kept.iter().copied()).any(|q| q.lcm.divides(&p.lcm));
+        if coprime || !chained {
+            kept.push(p);
+        }
+    }
+    let new_pairs: Vec<Pair<N, M>> = kept
+        .into_iter()
+        .filter(|p| !lm(p.i).is_coprime(&ht))
+        .map(|p| Pair { i: p.i, j: p.j, lcm: p.lcm })
+        .collect();
+    pairs.retain(|p| !ht.divides(&p.lcm) || lm(p.i).lcm(&ht) == p.lcm || lm(p.j).lcm(&ht) == p.lcm);
+    pairs.extend(new_pairs);
+    basis.retain(|&i| !ht.divides(&lm(i)));
+    basis.push(t);
+    polys.push(h);
+}
+
+/// The ideal generated by the polynomials `G::VAL()`. Elements are reduced to their normal form
+/// with respect to the reduced Gröbner basis, so a `QuotientRing` by this ideal has canonical
+/// representatives. The basis is computed once per thread and then cached for the type `G`.
+pub struct PolynomialIdeal<F, O, const N: usize, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    pub data: MultiPolynomial<F, O, N, M>,
+    pub g: PhantomData<G>,
+}
+impl<F, O, const N: usize, M, G> PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F> + 'static,
+    F: Field<O> + 'static,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder + 'static,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>> + 'static,
+{
+    /// The reduced Gröbner basis of this ideal.
+    pub fn basis() -> Vec<MultiPolynomial<F, O, N, M>> {
+        cached::<G, _>(|| groebner_basis(&G::VAL()))
+    }
+}
+impl<F, O, const N: usize, M, G> PartialEq for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+impl<F, O, const N: usize, M, G> Eq for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+}
+impl<F, O, const N: usize, M, G> Clone for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    fn clone(&self) -> Self {
+        Self { data: self.data.clone(), g: PhantomData }
+    }
+}
+impl<F, O, const N: usize, M, G> Set for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+}
+impl<F, O, const N: usize, M, G> Subset<MultiPolynomial<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F> + 'static,
+    F: Field<O> + 'static,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder + 'static,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>> + 'static,
+{
+    fn contains(t: &MultiPolynomial<F, O, N, M>) -> bool {
+        t.normal_form(&Self::basis()).is_zero()
+    }
+    fn inclusion(self) -> MultiPolynomial<F, O, N, M> {
+        self.data
+    }
+    fn try_from(t: MultiPolynomial<F, O, N, M>) -> Self {
+        if Self::contains(&t) {
+            Self { data: t, g: PhantomData }
+        } else {
+            panic!()
+        }
+    }
+}
+impl<F, O, const N: usize, M, G> O2<PolynomialIdeal<F, O, N, M, G>> for MPlus<F, O, N, M>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    const F: fn(PolynomialIdeal<F, O, N, M, G>, &PolynomialIdeal<F, O, N, M, G>) -> PolynomialIdeal<F, O, N, M, G> =
+        |a, b| PolynomialIdeal { data: a.data.plus(&b.data), g: PhantomData };
+}
+impl<F, O, const N: usize, M, G> Monoid<MPlus<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    fn identity() -> Self {
+        Self { data: MultiPolynomial::zero(), g: PhantomData }
+    }
+}
+impl<F, O, const N: usize, M, G> Group<MPlus<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+    M: MonomialOrder,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>>,
+{
+    fn inverse(self) -> Self {
+        Self { data: Ring::negated(self.data), g: PhantomData }
+    }
+}
+impl<F, O, const N: usize, M, G> Subgroup<MultiPolynomial<F, O, N, M>, MPlus<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F> + 'static,
+    F: Field<O> + 'static,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder + 'static,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>> + 'static,
+{
+}
+impl<F, O, const N: usize, M, G> AbsorbingSubset<MultiPolynomial<F, O, N, M>, MTimes<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F> + 'static,
+    F: Field<O> + 'static,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder + 'static,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>> + 'static,
+{
+    fn times(self, m: &MultiPolynomial<F, O, N, M>) -> Self {
+        Self { data: self.data.times(m), g: PhantomData }
+    }
+}
+impl<F, O, const N: usize, M, G> Ideal<MultiPolynomial<F, O, N, M>, MultiOps<F, O, N, M>> for PolynomialIdeal<F, O, N, M, G>
+where
+    O: RingOperations<F> + 'static,
+    F: Field<O> + 'static,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+    M: MonomialOrder + 'static,
+    G: Wrapper<Vec<MultiPolynomial<F, O, N, M>>> + 'static,
+{
+    fn reduce(r: MultiPolynomial<F, O, N, M>) -> MultiPolynomial<F, O, N, M> {
+        r.normal_form(&Self::basis())
+    }
+}
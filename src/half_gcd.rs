@@ -0,0 +1,125 @@
+use crate::{
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::Polynomial,
+    structure::{field::Field, group::Group, ring::{Ring, RingOperations}},
+};
+
+/// Below this degree the half-GCD does classical Euclidean steps.
+const HALF_GCD_THRESHOLD: usize = 64;
+
+/// A 2×2 matrix of polynomials, acting on pairs `(a, b)` as column vectors.
+pub type Matrix<F, O> = [[Polynomial<F, O>; 2]; 2];
+
+fn identity<F: Ring<O>, O: RingOperations<F>>() -> Matrix<F, O> {
+    [[Polynomial::one(), Polynomial::zero()], [Polynomial::zero(), Polynomial::one()]]
+}
+fn mat_mul<F: Ring<O>, O: RingOperations<F>>(a: &Matrix<F, O>, b: &Matrix<F, O>) -> Matrix<F, O> {
+    let entry = |i: usize, j: usize| a[i][0].clone().times(&b[0][j]).plus(&a[i][1].clone().times(&b[1][j]));
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+fn apply<F: Ring<O>, O: RingOperations<F>>(m: &Matrix<F, O>, a: &Polynomial<F, O>, b: &Polynomial<F, O>) -> (Polynomial<F, O>, Polynomial<F, O>) {
+    let row = |i: usize| m[i][0].clone().times(a).plus(&m[i][1].clone().times(b));
+    (row(0), row(1))
+}
+/// `a` divided by `x^k`, dropping the remainder.
+fn shift<F: Ring<O>, O: RingOperations<F>>(a: &Polynomial<F, O>, k: usize) -> Polynomial<F, O> {
+    Polynomial::from_coefficients(a.coefficients.get(k..).unwrap_or_default().to_vec())
+}
+
+impl<F, O> Polynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// The Euclidean step `(a, b) -> (b, a mod b)`, with its matrix `[[0, 1], [1, -q]]` multiplied
+    /// onto `m` from the left.
+    fn euclidean_step(m: Matrix<F, O>, a: Self, b: Self) -> (Matrix<F, O>, Self, Self) {
+        let (q, r) = Self::divide(a, &b);
+        let [[m00, m01], [m10, m11]] = m;
+        let next = [m10.clone(), m11.clone()];
+        let last = [m00.minus(&q.clone().times(&m10)), m01.minus(&q.times(&m11))];
+        ([next, last], b, r)
+    }
+    /// Classical Euclidean steps from `(a, b)` while `deg b >= bound`.
+    fn classical_steps(a: &Self, b: &Self, bound: usize) -> Matrix<F, O> {
+        let (mut m, mut a, mut b) = (identity(), a.clone(), b.clone());
+        while b.degree() >= bound {
+            (m, a, b) = Self::euclidean_step(m, a, b);
+        }
+        m
+    }
+    /// The half-GCD of `a` and `b`, where `deg a > deg b`: the product `M` of the Euclidean steps
+    /// that take `(a, b)` to the consecutive remainders `(c, d) = M (a, b)` with
+    /// `deg c >= ceil(deg a / 2) > deg d`. Both recursive calls are on polynomials of half the
+    /// degree, found by dropping the low coefficients, whose quotients agree with those of `a` and
+    /// `b` for as long as the degrees stay high. So this takes `O(M(n) log n)` operations, where
+    /// `M(n)` is the cost of a product, against `O(n^2)` for the classical algorithm.
+    pub fn half_gcd(a: &Self, b: &Self) -> Matrix<F, O> {
+        assert!(a.degree() > b.degree(), "the half-GCD needs deg a > deg b");
+        let n = a.degree().unwrap();
+        let m = n.div_ceil(2);
+        if b.degree() < m {
+            return identity();
+        }
+        if n < HALF_GCD_THRESHOLD {
+            return Self::classical_steps(a, b, m);
+        }
+        let r = Self::half_gcd(&shift(a, m), &shift(b, m));
+        let (c, d) = apply(&r, a, b);
+        if d.degree() < m {
+            return r;
+        }
+        let (r, c, d) = Self::euclidean_step(r, c, d);
+        if d.degree() < m {
+            return r;
+        }
+        let k = 2 * m - c.degree().unwrap();
+        mat_mul(&Self::half_gcd(&shift(&c, k), &shift(&d, k)), &r)
+    }
+    /// The product `M` of all the Euclidean steps from `(a, b)`, where `deg a > deg b`, so that
+    /// `M (a, b) = (gcd, 0)`. Each half-GCD at least halves the degree.
+    fn euclidean_matrix(a: &Self, b: &Self) -> Matrix<F, O> {
+        if b.coefficients.is_empty() {
+            return identity();
+        }
+        if a.degree() < HALF_GCD_THRESHOLD {
+            return Self::classical_steps(a, b, 0);
+        }
+        let m = Self::half_gcd(a, b);
+        let (c, d) = apply(&m, a, b);
+        if d.coefficients.is_empty() {
+            return m;
+        }
+        let (m, c, d) = Self::euclidean_step(m, c, d);
+        mat_mul(&Self::euclidean_matrix(&c, &d), &m)
+    }
+    /// The same gcd and Bézout coefficients as classical Euclid, that is, the last nonzero
+    /// remainder and its cofactors: `(g, x, y)` with `ax + by = g`.
+    pub(crate) fn fast_bézout(a: Self, b: Self) -> (Self, Self, Self) {
+        if b.coefficients.is_empty() {
+            return (a, Self::one(), Self::zero());
+        }
+        let (q, r) = Self::divide(a, &b);
+        let m = Self::euclidean_matrix(&b, &r);
+        let (g, _) = apply(&m, &b, &r);
+        // g = m00 b + m01 (a - q b).
+        let [[m00, m01], _] = m;
+        let y = m00.minus(&q.times(&m01));
+        (g, m01, y)
+    }
+    /// The last nonzero remainder, like classical Euclid. Small inputs skip the matrices.
+    pub(crate) fn fast_gcd(mut a: Self, mut b: Self) -> Self {
+        while !b.coefficients.is_empty() {
+            let r = Self::divide(a, &b).1;
+            if b.degree() >= HALF_GCD_THRESHOLD {
+                let m = Self::euclidean_matrix(&b, &r);
+                return apply(&m, &b, &r).0;
+            }
+            (a, b) = (b, r);
+        }
+        a
+    }
+}
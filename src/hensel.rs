@@ -0,0 +1,318 @@
+use std::cell::Cell;
+
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::{
+    arithmetic::is_prime,
+    factor::Factorization,
+    impls::StandardOps,
+    polynomial::Polynomial,
+    quotient::{PrimeModulus, Residues},
+    structure::{euclidean_ring::EuclideanRing, field::Field, ring::Ring},
+    wrapper::Wrapper,
+};
+
+type ZX = Polynomial<BigInt, StandardOps>;
+
+/// The factorization of a polynomial modulo a prime `p`, ready for Hensel lifting: its monic
+/// irreducible factors `g_i`, and `s_i` with `deg s_i < deg g_i` and
+/// `sum s_i * prod_{j != i} g_j = 1` mod `p`. Polynomials are given by residues in `0..p`.
+struct ModularFactors {
+    p: i64,
+    factors: Vec<Vec<i64>>,
+    s: Vec<Vec<i64>>,
+}
+
+thread_local! {
+    static PRIME: Cell<i64> = const { Cell::new(3) };
+}
+/// The prime of the modular factorization. Primes are searched for at run time, so it cannot be
+/// a const generic like `IntegersMod<P>`; its value is set by a `PrimeScope`, which checks that it
+/// is prime. Values of `FX` only mean anything inside that scope, so none may leave
+/// `modular_factors`.
+struct HenselPrime {}
+impl Wrapper<i64> for HenselPrime {
+    const VAL: fn() -> i64 = || PRIME.with(Cell::get);
+}
+impl PrimeModulus for HenselPrime {}
+type FX = Polynomial<Residues<HenselPrime>, StandardOps>;
+
+/// Sets the prime of `HenselPrime` to `p` until dropped, then restores the previous one, so that
+/// a factorization nested in another on the same thread leaves the outer prime in place.
+struct PrimeScope {
+    previous: i64,
+}
+impl PrimeScope {
+    /// Panics unless `p` is prime, the check `PrimeWrap` does at compile time.
+    fn new(p: i64) -> Self {
+        assert!(p > 0 && is_prime(p as u64), "the modulus must be prime");
+        PrimeScope {
+            previous: PRIME.with(|prime| prime.replace(p)),
+        }
+    }
+}
+impl Drop for PrimeScope {
+    fn drop(&mut self) {
+        PRIME.with(|prime| prime.set(self.previous));
+    }
+}
+
+/// Factors `g` mod the prime `p`, provided `p` does not divide its leading coefficient and `g`
+/// stays square-free mod `p`.
+fn modular_factors(g: &ZX, p: i64) -> Option<ModularFactors> {
+    let _scope = PrimeScope::new(p);
+    let gp = FX::from_coefficients(
+        g.coefficients
+            .iter()
+            .map(|c| Residues::from((c % p).to_i64().unwrap()))
+            .collect(),
+    );
+    if gp.degree() != g.degree() || FX::gcd(gp.clone(), gp.derivative()).degree() > 0 {
+        return None;
+    }
+    let factors: Vec<FX> = gp.factor().1.into_iter().map(|(h, _)| h).collect();
+    let mut s = vec![];
+    for (i, gi) in factors.iter().enumerate() {
+        let others = factors
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(FX::one(), |a, (_, h)| a * h.clone());
+        // a*gi + b*others is a nonzero constant, as gi and the others are coprime.
+        let (a, b) = FX::bézout(gi.clone(), others.clone());
+        let d = a.times(gi).plus(&b.clone().times(&others)).lead_coeff();
+        s.push(b.times(&FX::constant(d.reciprocal())).remainder(gi));
+    }
+    // Only residues leave this function, and they must be read mod the prime they were found mod.
+    debug_assert_eq!(HenselPrime::VAL(), p, "the Hensel prime changed during the factorization");
+    let residues = |h: &FX| h.coefficients.iter().map(Residues::residue).collect();
+    Some(ModularFactors {
+        p,
+        factors: factors.iter().map(residues).collect(),
+        s: s.iter().map(residues).collect(),
+    })
+}
+
+/// The odd primes, in increasing order, tried for the modular factorization. They are kept below
+/// `2^31` so that products of residues fit in an `i64`.
+fn primes() -> impl Iterator<Item = i64> {
+    (3..1 << 31).step_by(2).filter(|&p| is_prime(p as u64))
+}
+/// How many suitable primes to factor modulo before settling on the one with fewest factors.
+const PRIME_TRIALS: usize = 3;
+
+fn from_residues(v: &[i64]) -> ZX {
+    ZX::from_coefficients(v.iter().map(|c| BigInt::from(*c)).collect())
+}
+/// Reduces every coefficient into `0..m`.
+fn reduce(f: &ZX, m: &BigInt) -> ZX {
+    ZX::from_coefficients(f.coefficients.iter().map(|c| c.clone().remainder(m)).collect())
+}
+/// Reduces every coefficient into `(-m/2, m/2]`.
+fn reduce_symmetric(f: &ZX, m: &BigInt) -> ZX {
+    let half: BigInt = m / 2;
+    ZX::from_coefficients(
+        f.coefficients
+            .iter()
+            .map(|c| {
+                let c = c.clone().remainder(m);
+                if c > half {
+                    c - m
+                } else {
+                    c
+                }
+            })
+            .collect(),
+    )
+}
+/// `f / g` if `g` divides `f` in `Z[x]`, otherwise `None`.
+fn exact_quotient(f: &ZX, g: &ZX) -> Option<ZX> {
+    let n = g.degree().unwrap();
+    let lc = g.lead_coeff();
+    let mut r = f.coefficients.clone();
+    if r.len() <= n {
+        return if r.is_empty() { Some(ZX::zero()) } else { None };
+    }
+    let mut q = vec![BigInt::from(0); r.len() - n];
+    for k in (n..r.len()).rev() {
+        if !(&r[k] % &lc).is_zero() {
+            return None;
+        }
+        let c = &r[k] / &lc;
+        for (i, d) in g.coefficients.iter().enumerate() {
+            r[k - n + i] -= &c * d;
+        }
+        q[k - n] = c;
+    }
+    if r.iter().all(Zero::is_zero) {
+        Some(ZX::from_coefficients(q))
+    } else {
+        None
+    }
+}
+/// Yun's square-free decomposition of a primitive polynomial with positive leading coefficient,
+/// carried out in `Z[x]` with primitive gcds.
+fn square_free(f: &ZX) -> Vec<(ZX, usize)> {
+    let df = f.derivative();
+    let a = ZX::subresultant_gcd(f, &df);
+    let mut b = exact_quotient(f, &a).unwrap();
+    let mut d = exact_quotient(&df, &a).unwrap().minus(&b.derivative());
+    let mut res = vec![];
+    let mut i = 1;
+    while b.degree() > 0 {
+        let a = ZX::subresultant_gcd(&b, &d);
+        b = exact_quotient(&b, &a).unwrap();
+        d = exact_quotient(&d, &a).unwrap().minus(&b.derivative());
+        if a.degree() > 0 {
+            res.push((a, i));
+        }
+        i += 1;
+    }
+    res
+}
+/// Lifts `g = lc(g) * prod g_i` mod `p` to the same factorization mod `p^k` for the least `k`
+/// with `p^k > bound`, one power of `p` at a time. Returns the lifted monic factors and `p^k`.
+fn hensel_lift(g: &ZX, modular: &ModularFactors, bound: &BigInt) -> (Vec<ZX>, BigInt) {
+    let p = BigInt::from(modular.p);
+    let lc = g.lead_coeff();
+    let lc_inverse = ZX::constant(lc.clone().remainder(&p).modpow(&(&p - 2), &p));
+    let s: Vec<ZX> = modular.s.iter().map(|v| from_residues(v)).collect();
+    let mut factors: Vec<ZX> = modular.factors.iter().map(|v| from_residues(v)).collect();
+    let mut m = p.clone();
+    while &m <= bound {
+        let product = factors.iter().fold(ZX::constant(lc.clone()), |a, h| a * h.clone());
+        let e = ZX::from_coefficients(
+            reduce(&g.clone().minus(&product), &(&m * &p))
+                .coefficients
+                .into_iter()
+                .map(|c| c / &m)
+                .collect(),
+        );
+        let e = reduce(&(e * lc_inverse.clone()), &p);
+        for (gi, si) in factors.iter_mut().zip(&s) {
+            let delta = reduce(&ZX::divide(reduce(&(e.clone() * si.clone()), &p), gi).1, &p);
+            *gi = gi.clone().plus(&(delta * ZX::constant(m.clone())));
+        }
+        m *= &p;
+    }
+    (factors, m)
+}
+/// Zassenhaus recombination: finds the subsets of the lifted factors whose product, times the
+/// leading coefficient and reduced symmetrically mod `m`, gives a true factor of `g`.
+fn recombine(mut g: ZX, mut lifted: Vec<ZX>, m: &BigInt) -> Vec<ZX> {
+    let mut res = vec![];
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let mut found = false;
+        let mut subset: Vec<usize> = (0..size).collect();
+        loop {
+            let candidate = subset
+                .iter()
+                .fold(ZX::constant(g.lead_coeff()), |a, &i| a * lifted[i].clone());
+            let h = reduce_symmetric(&candidate, m).primitive_part();
+            if let Some(q) = exact_quotient(&g, &h) {
+                res.push(h);
+                g = q;
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                }
+                found = true;
+                break;
+            }
+            if !next_subset(&mut subset, lifted.len()) {
+                break;
+            }
+        }
+        if !found {
+            size += 1;
+        }
+    }
+    if g.degree() > 0 {
+        res.push(g.primitive_part());
+    }
+    res
+}
+/// Advances `subset` to the next increasing sequence of indices below `n` in lexicographic
+/// order. Returns false once there are none left.
+fn next_subset(subset: &mut [usize], n: usize) -> bool {
+    let k = subset.len();
+    for i in (0..k).rev() {
+        if subset[i] < n - k + i {
+            subset[i] += 1;
+            for j in i + 1..k {
+                subset[j] = subset[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+/// The irreducible factors of a primitive square-free polynomial of positive degree.
+fn factor_square_free(g: &ZX) -> Vec<ZX> {
+    let n = g.degree().unwrap();
+    if n == 1 {
+        return vec![g.clone()];
+    }
+    // Only the finitely many primes dividing the leading coefficient or the discriminant, which is
+    // nonzero as g is square-free, are unsuitable, and they are far fewer than the primes below
+    // 2^31.
+    let modular = primes()
+        .filter_map(|p| modular_factors(g, p))
+        .take(PRIME_TRIALS)
+        .min_by_key(|m| m.factors.len())
+        .expect("no suitable prime below 2^31 for the modular factorization");
+    if modular.factors.len() == 1 {
+        return vec![g.clone()];
+    }
+    // Landau–Mignotte: every factor of g has coefficients at most 2^n * |g|_2 <= 2^n * |g|_1.
+    // Candidates are multiplied by lc(g), and must be recovered from their symmetric residues.
+    let norm: BigInt = g.coefficients.iter().map(|c| c.abs()).sum();
+    let bound = (norm << n) * g.lead_coeff().abs() * 2;
+    let (lifted, m) = hensel_lift(g, &modular, &bound);
+    recombine(g.clone(), lifted, &m)
+}
+
+impl Polynomial<BigInt, StandardOps> {
+    /// Factors a nonzero integer polynomial as its content times irreducible primitive factors
+    /// with positive leading coefficients, with multiplicities, sorted by degree.
+    ///
+    /// Each square-free part is factored modulo a small prime, the factors are Hensel-lifted
+    /// using the Bézout coefficients from `EuclideanRing::bézout` until they determine every
+    /// possible integer factor, and true factors are recovered by trying products of subsets of
+    /// the lifted factors (Zassenhaus). That last step is exponential in the worst case, which
+    /// needs many more modular factors than integer ones.
+    pub fn factor(&self) -> Factorization<BigInt, StandardOps> {
+        assert!(!self.coefficients.is_empty(), "zero cannot be factored");
+        let mut res = vec![];
+        for (g, m) in square_free(&self.primitive_part()) {
+            for h in factor_square_free(&g) {
+                res.push((h, m));
+            }
+        }
+        res.sort_by(|(a, m), (b, n)| {
+            (a.coefficients.len(), &a.coefficients, m).cmp(&(b.coefficients.len(), &b.coefficients, n))
+        });
+        (self.content(), res)
+    }
+}
+impl Polynomial<i64, StandardOps> {
+    /// See the `BigInt` version. Panics if a factor has a coefficient that does not fit in an
+    /// `i64`, which can happen even when all coefficients of `self` do.
+    pub fn factor(&self) -> Factorization<i64, StandardOps> {
+        let big = ZX::from_coefficients(self.coefficients.iter().map(|c| BigInt::from(*c)).collect());
+        let (content, factors) = big.factor();
+        let small = |f: ZX| {
+            Polynomial::from_coefficients(
+                f.coefficients
+                    .iter()
+                    .map(|c| c.to_i64().expect("factor coefficient overflows i64"))
+                    .collect(),
+            )
+        };
+        (
+            content.to_i64().unwrap(),
+            factors.into_iter().map(|(f, m)| (small(f), m)).collect(),
+        )
+    }
+}
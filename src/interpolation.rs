@@ -0,0 +1,148 @@
+use crate::{
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::Polynomial,
+    structure::{field::Field, group::Group, ring::{Ring, RingOperations}},
+};
+
+/// The subproduct tree of the points `x_0, ..., x_(n-1)`: the leaves are `x - x_i`, and each node
+/// is the product of its two children. A node without a sibling is carried up unchanged. Level 0
+/// holds the leaves and the last level holds the root, `prod (x - x_i)`.
+pub struct SubproductTree<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    pub levels: Vec<Vec<Polynomial<F, O>>>,
+}
+impl<F, O> SubproductTree<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    pub fn new(points: &[F]) -> Self {
+        let mut levels = vec![points
+            .iter()
+            .map(|x| Polynomial::from_coefficients(vec![x.clone().negated(), F::one()]))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a.clone().times(b),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        SubproductTree { levels }
+    }
+    /// `prod (x - x_i)`, the monic polynomial vanishing exactly at the points.
+    pub fn root(&self) -> Polynomial<F, O> {
+        match self.levels.last().unwrap().first() {
+            Some(m) => m.clone(),
+            None => Polynomial::one(),
+        }
+    }
+    /// `f(x_i)` for every point, by reducing `f` modulo each node on the way down the tree.
+    pub fn evaluate(&self, f: &Polynomial<F, O>) -> Vec<F> {
+        if self.levels[0].is_empty() {
+            return vec![];
+        }
+        let mut remainders = vec![f.clone()];
+        for level in self.levels.iter().rev() {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, m)| Polynomial::divide(remainders[i / 2].clone(), m).1)
+                .collect();
+        }
+        remainders.iter().map(|r| r.coefficient(0)).collect()
+    }
+    /// `sum c_i * prod_(j != i) (x - x_j)`, by combining the two halves at each node on the way up.
+    pub fn linear_combination(&self, c: &[F]) -> Polynomial<F, O> {
+        let mut sums: Vec<Polynomial<F, O>> = c.iter().map(|c| Polynomial::constant(c.clone())).collect();
+        for level in &self.levels[..self.levels.len() - 1] {
+            sums = sums
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|pair| match pair {
+                    ([s, t], [m, n]) => s.clone().times(n).plus(&t.clone().times(m)),
+                    _ => pair.0[0].clone(),
+                })
+                .collect();
+        }
+        sums.pop().unwrap_or_else(Polynomial::zero)
+    }
+}
+
+impl<F, O> Polynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// Evaluates at every point using a subproduct tree. The cost is a constant number of
+    /// products and divisions at each level of the tree, rather than one Horner pass per point.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        SubproductTree::new(points).evaluate(self)
+    }
+    /// The unique polynomial of degree less than `n` through the `n` samples `(x_i, y_i)`, by the
+    /// subproduct tree: with `M = prod (x - x_i)`, it is `sum y_i / M'(x_i) * M / (x - x_i)`.
+    /// Panics if two samples share an `x`.
+    pub fn interpolate(samples: &[(F, F)]) -> Self {
+        let points: Vec<F> = samples.iter().map(|(x, _)| x.clone()).collect();
+        let tree = SubproductTree::new(&points);
+        let weights: Vec<F> = tree
+            .evaluate(&tree.root().derivative())
+            .into_iter()
+            .zip(samples)
+            .map(|(d, (_, y))| y.clone().times(&d.reciprocal()))
+            .collect();
+        tree.linear_combination(&weights)
+    }
+    /// The same polynomial as `interpolate`, from the Lagrange basis polynomials
+    /// `prod_(j != i) (x - x_j) / (x_i - x_j)` with quadratically many operations.
+    /// Panics if two samples share an `x`.
+    pub fn lagrange(samples: &[(F, F)]) -> Self {
+        let m = samples.iter().fold(Self::one(), |m, (x, _)| {
+            m.times(&Self::from_coefficients(vec![x.clone().negated(), F::one()]))
+        });
+        let mut res = Self::zero();
+        for (i, (xi, yi)) in samples.iter().enumerate() {
+            let denominator = samples
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(F::one(), |d, (_, (xj, _))| d.times(&xi.clone().minus(xj)));
+            let basis = Self::divide(m.clone(), &Self::from_coefficients(vec![xi.clone().negated(), F::one()])).0;
+            res = res.plus(&basis.times(&Self::constant(yi.clone().times(&denominator.reciprocal()))));
+        }
+        res
+    }
+    /// The same polynomial as `interpolate`, in Newton form
+    /// `c_0 + c_1 (x - x_0) + c_2 (x - x_0)(x - x_1) + ...` with the divided differences `c_k`.
+    /// Samples can be added one at a time by extending the divided differences.
+    /// Panics if two samples share an `x`.
+    pub fn newton(samples: &[(F, F)]) -> Self {
+        let mut c: Vec<F> = samples.iter().map(|(_, y)| y.clone()).collect();
+        for k in 1..c.len() {
+            for i in (k..c.len()).rev() {
+                let dx = samples[i].0.clone().minus(&samples[i - k].0);
+                c[i] = c[i].clone().minus(&c[i - 1]).times(&dx.reciprocal());
+            }
+        }
+        let mut res = Self::zero();
+        for (ck, (xk, _)) in c.iter().zip(samples).rev() {
+            res = res
+                .times(&Self::from_coefficients(vec![xk.clone().negated(), F::one()]))
+                .plus(&Self::constant(ck.clone()));
+        }
+        res
+    }
+}
@@ -0,0 +1,294 @@
+use std::{marker::PhantomData, ops::{Add, Mul}};
+use crate::{
+    operation::O2,
+    polynomial::Polynomial,
+    set::Set,
+    structure::{group::Group, monoid::Monoid, ring::{Ring, RingOperations}},
+    unit::TryInverse,
+};
+
+/// A Laurent polynomial `x^valuation * (c_0 + c_1 x + ... + c_n x^n)` over `R`, which may have
+/// negative exponents. The coefficients have no zeros at either end, so the valuation is the
+/// least exponent with a nonzero coefficient; zero has no coefficients and valuation zero.
+pub struct LaurentPolynomial<R, O: RingOperations<R>>
+where
+    R: Ring<O>,
+{
+    pub valuation: i64,
+    pub coefficients: Vec<R>,
+    pub o: PhantomData<O>,
+}
+impl<R, O> LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    /// `x^valuation` times the polynomial with the given coefficients, constant term first.
+    /// Zeros at either end are removed.
+    pub fn new(valuation: i64, mut coefficients: Vec<R>) -> Self {
+        while coefficients.last() == Some(&R::zero()) {
+            coefficients.pop();
+        }
+        let leading_zeros = coefficients.iter().take_while(|c| **c == R::zero()).count();
+        if leading_zeros == coefficients.len() {
+            return Self::zero();
+        }
+        coefficients.drain(..leading_zeros);
+        LaurentPolynomial { valuation: valuation + leading_zeros as i64, coefficients, o: PhantomData }
+    }
+    pub fn zero() -> Self {
+        LaurentPolynomial { valuation: 0, coefficients: vec![], o: PhantomData }
+    }
+    pub fn one() -> Self {
+        Self::constant(R::one())
+    }
+    pub fn constant(c: R) -> Self {
+        Self::monomial(c, 0)
+    }
+    /// `c x^k`.
+    pub fn monomial(c: R, k: i64) -> Self {
+        Self::new(k, vec![c])
+    }
+    pub fn x() -> Self {
+        Self::monomial(R::one(), 1)
+    }
+    pub fn x_inverse() -> Self {
+        Self::monomial(R::one(), -1)
+    }
+    /// The least exponent with a nonzero coefficient, or `None` for zero.
+    pub fn valuation(&self) -> Option<i64> {
+        if self.coefficients.is_empty() {
+            None
+        } else {
+            Some(self.valuation)
+        }
+    }
+    /// The greatest exponent with a nonzero coefficient, or `None` for zero. Unlike the `Degree`
+    /// of a `Polynomial`, this can be negative.
+    pub fn degree(&self) -> Option<i64> {
+        self.valuation().map(|v| v + self.coefficients.len() as i64 - 1)
+    }
+    /// The coefficient of `x^k`.
+    pub fn coefficient(&self, k: i64) -> R {
+        usize::try_from(k - self.valuation)
+            .ok()
+            .and_then(|i| self.coefficients.get(i).cloned())
+            .unwrap_or_else(R::zero)
+    }
+    /// `x^k * self`, which only shifts the valuation.
+    pub fn multiply_by_x_pow(mut self, k: i64) -> Self {
+        if !self.coefficients.is_empty() {
+            self.valuation += k;
+        }
+        self
+    }
+    /// The polynomial equal to `self`, if it has no negative exponents.
+    pub fn to_polynomial(&self) -> Option<Polynomial<R, O>> {
+        let shift = usize::try_from(self.valuation).ok()?;
+        let mut coefficients = vec![R::zero(); shift];
+        coefficients.extend(self.coefficients.iter().cloned());
+        Some(Polynomial::from_coefficients(coefficients))
+    }
+    /// `self(x^-1)`, the involution that reverses every exponent.
+    pub fn substitute_x_inverse(&self) -> Self {
+        match self.degree() {
+            Some(d) => Self::new(-d, self.coefficients.iter().rev().cloned().collect()),
+            None => Self::zero(),
+        }
+    }
+    fn add(self, other: &Self) -> Self {
+        if other.coefficients.is_empty() {
+            return self;
+        }
+        if self.coefficients.is_empty() {
+            return other.clone();
+        }
+        let valuation = self.valuation.min(other.valuation);
+        let degree = self.degree().unwrap().max(other.degree().unwrap());
+        let coefficients = (valuation..=degree)
+            .map(|k| self.coefficient(k).plus(&other.coefficient(k)))
+            .collect();
+        Self::new(valuation, coefficients)
+    }
+    fn mul(self, other: &Self) -> Self {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Self::zero();
+        }
+        let product = Polynomial::<R, O>::from_coefficients(self.coefficients)
+            .times(&Polynomial::from_coefficients(other.coefficients.clone()));
+        Self::new(self.valuation + other.valuation, product.coefficients)
+    }
+    fn negated(self) -> Self {
+        LaurentPolynomial {
+            valuation: self.valuation,
+            coefficients: self.coefficients.into_iter().map(R::negated).collect(),
+            o: PhantomData,
+        }
+    }
+}
+impl<R, O> LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + TryInverse<O>,
+{
+    /// Evaluates at `x`, or returns `None` if there are negative exponents and `x` is not a unit.
+    pub fn of(&self, x: R) -> Option<R> {
+        let mut res = R::zero();
+        for c in self.coefficients.iter().rev() {
+            res = res.times(&x).plus(c);
+        }
+        let power = if self.valuation >= 0 { x } else { x.try_inverse()? };
+        Some(res.times(&Ring::pow(power, self.valuation.unsigned_abs())))
+    }
+}
+/// The units are the monomials `c x^k` with `c` a unit of `R`, at least when `R` is a domain.
+impl<R, O> TryInverse<LaurentOps<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + TryInverse<O>,
+{
+    fn try_inverse(self) -> Option<Self> {
+        match self.coefficients.as_slice() {
+            [c] => Some(Self::monomial(c.clone().try_inverse()?, -self.valuation)),
+            _ => None,
+        }
+    }
+}
+impl<R, O> From<Polynomial<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn from(f: Polynomial<R, O>) -> Self {
+        Self::new(0, f.coefficients)
+    }
+}
+impl<R, O> Add<LaurentPolynomial<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+impl<R, O> Mul<LaurentPolynomial<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+pub struct LPlus<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+pub struct LTimes<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O> O2<LaurentPolynomial<R, O>> for LPlus<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(LaurentPolynomial<R, O>, &LaurentPolynomial<R, O>) -> LaurentPolynomial<R, O> = <LaurentPolynomial<R, O>>::add;
+}
+impl<R, O> O2<LaurentPolynomial<R, O>> for LTimes<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(LaurentPolynomial<R, O>, &LaurentPolynomial<R, O>) -> LaurentPolynomial<R, O> = <LaurentPolynomial<R, O>>::mul;
+}
+impl<R, O> PartialEq for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.valuation == other.valuation && self.coefficients == other.coefficients
+    }
+}
+impl<R, O> Eq for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O> Clone for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn clone(&self) -> Self {
+        LaurentPolynomial { valuation: self.valuation, coefficients: self.coefficients.clone(), o: PhantomData }
+    }
+}
+impl<R, O> Set for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O> Monoid<LTimes<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+impl<R, O> Monoid<LPlus<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+impl<R, O> Group<LPlus<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn inverse(self) -> Self {
+        self.negated()
+    }
+}
+pub struct LaurentOps<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O> RingOperations<LaurentPolynomial<R, O>> for LaurentOps<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type PLUS = LPlus<R, O>;
+    type TIMES = LTimes<R, O>;
+}
+impl<R, O> Ring<LaurentOps<R, O>> for LaurentPolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
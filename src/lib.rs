@@ -9,18 +9,39 @@ mod wrapper;
 mod test;
 mod unit;
 mod impls;
+mod ntt;
+mod resultant;
+mod factor;
+mod hensel;
+mod multivariate;
+mod groebner;
+mod cache;
+mod sparse;
+mod interpolation;
+mod crt;
+mod format;
+mod real_roots;
+mod laurent;
+mod power_series;
+mod cyclotomic;
+mod division;
+mod half_gcd;
+mod rational_function;
+mod arithmetic;
 extern crate take_mut;
 extern crate num_bigint;
 #[cfg(test)]
 mod tests {
     use std::marker::PhantomData;
 
+    use num_bigint::BigInt;
+
     use crate::{
         polynomial::Polynomial,
-        quotient::{ QuotientGroup, QuotientRing, IntMultiples},
-        structure::{ring::{Ring}, group::Group, monoid::Monoid, euclidean_ring::EuclideanRing},
+        quotient::{ QuotientGroup, QuotientRing, QuotientProduct, IntMultiples, IntegersMod},
+        structure::{ring::{Ring, RingOperations}, group::Group, monoid::Monoid, euclidean_ring::EuclideanRing, field::Field},
         set::{Subset}, impls::StandardOps,
-        impls
+        impls, ntt
     };
 
     #[test]
@@ -54,4 +75,1052 @@ mod tests {
             println!("{:?}",(-5i64).div_euclid(-3))
         };
     }
+
+    // The helpers trim, so that random coefficients still give canonical polynomials.
+    fn poly(coefficients: Vec<i64>) -> Polynomial<i64, StandardOps> {
+        Polynomial::from_coefficients(coefficients)
+    }
+
+    #[test]
+    fn derivative_and_taylor_shift() {
+        let f = poly(vec![1, 2, 0, -1]);
+        assert!(f.derivative() == poly(vec![2, 0, -3]));
+        assert!(f.nth_derivative(2) == poly(vec![0, -6]));
+        assert!(f.nth_derivative(3) == poly(vec![-6]));
+        assert!(f.nth_derivative(4) == poly(vec![]));
+        assert!(f.taylor_shift(&1) == poly(vec![2, -1, -3, -1]));
+        assert!(f.taylor_shift(&1).taylor_shift(&-1) == f);
+        for a in -3..=3 {
+            assert_eq!(f.taylor_shift(&a).of(0), f.of(a));
+        }
+    }
+
+    #[test]
+    fn x_is_the_variable() {
+        // x used to have the coefficients [1, 0], an untrimmed constant 1.
+        let x = Polynomial::<i64, StandardOps>::x();
+        assert!(x.coefficients == vec![0, 1]);
+        assert!(x == Polynomial::x_pow(1));
+        assert!(x.degree() == 1);
+        for a in -3..=3 {
+            assert_eq!(x.of(a), a);
+        }
+        assert!(poly(vec![1, 2, 3]).compose(&x) == poly(vec![1, 2, 3]));
+    }
+
+    fn poly_mod<const P: i64>(coefficients: Vec<i64>) -> Polynomial<IntegersMod<P>, StandardOps> {
+        Polynomial::from_coefficients(coefficients.into_iter().map(IntegersMod::from).collect())
+    }
+
+    #[test]
+    fn prime_field() {
+        type F = IntegersMod<97>;
+        for a in 1..97 {
+            let a = F::from(a);
+            assert!(a.clone().reciprocal().times(&a) == F::one());
+        }
+        assert_eq!(F::from(-3).residue(), 94);
+        use crate::nonzero::NonZero;
+        type N = NonZero<F, StandardOps>;
+        let (a, b) = (<N as Subset<F>>::try_from(F::from(12)), <N as Subset<F>>::try_from(F::from(50)));
+        assert!(Monoid::<QuotientProduct<impls::Times>>::star(a, &b).inclusion() == F::from(600));
+        assert!(<N as Monoid<QuotientProduct<impls::Times>>>::identity().inclusion() == F::one());
+        // The integers mod a composite are only a ring; IntegersMod<4> does not compile.
+        type Z4 = QuotientRing<i64, StandardOps, IntMultiples<4>>;
+        assert!(Z4::from(2).times(&Z4::from(2)) == Z4::zero());
+        assert_eq!(Z4::from(-1).residue(), 3);
+    }
+
+    #[test]
+    fn nonzero_and_integer_remainder() {
+        use crate::nonzero::NonZero;
+        type N = NonZero<i64, StandardOps>;
+        assert!(!N::contains(&0));
+        assert!(N::contains(&5) && N::contains(&-5));
+        assert_eq!(<N as Subset<i64>>::try_from(-5).inclusion(), -5);
+        assert!(std::panic::catch_unwind(|| <N as Subset<i64>>::try_from(0)).is_err());
+        // Remainders are in 0..|d|, so that equal classes mod d have equal remainders.
+        for (n, d) in [(7, 3), (-7, 3), (7, -3), (-7, -3), (6, -3)] {
+            let (q, r) = EuclideanRing::divide(n, &d);
+            assert!((0..d.abs()).contains(&r));
+            assert_eq!(q * d + r, n);
+            assert_eq!(n.remainder(&d), r);
+        }
+        assert_eq!((-7i64).remainder(&3), 8i64.remainder(&3));
+    }
+
+    #[test]
+    fn composition() {
+        let f = poly(vec![1, 2, 0, -1]);
+        let g = poly(vec![1, 0, 1]);
+        let fg = f.compose(&g);
+        assert!(fg.degree() == 6);
+        for a in -3..=3 {
+            assert_eq!(fg.of(a), f.of(g.of(a)));
+        }
+        let f = poly_mod::<7>(vec![3, 1, 0, 5, 2, 6]);
+        let g = poly_mod::<7>(vec![1, 4, 0, 2]);
+        let h = poly_mod::<7>(vec![2, 0, 1, 1]);
+        assert!(f.compose_mod(&g, &h) == f.compose(&g).remainder(&h));
+    }
+
+    /// A small linear congruential generator, so that tests are reproducible.
+    fn pseudo_random(seed: &mut u64, bound: i64) -> i64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((*seed >> 33) % bound as u64) as i64
+    }
+
+    fn naive_product<R: Ring<O>, O: RingOperations<R>>(a: &[R], b: &[R]) -> Vec<R> {
+        let mut res = vec![R::zero(); a.len() + b.len() - 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                res[i + j] = res[i + j].clone().plus(&a[i].clone().times(&b[j]));
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn fast_multiplication() {
+        let mut seed = 1;
+        for (m, n) in [(400, 400), (350, 900), (40, 1000), (5, 300)] {
+            let a: Vec<i64> = (0..m).map(|_| pseudo_random(&mut seed, 2001) - 1000).collect();
+            let b: Vec<i64> = (0..n).map(|_| pseudo_random(&mut seed, 2001) - 1000).collect();
+            let expected = poly(naive_product(&a, &b));
+            let (a, b) = (poly(a), poly(b));
+            assert!(a.clone() * b.clone() == expected);
+            assert!(b * a == expected);
+        }
+        // Toom-3 divides by 2 and 3, so these composite moduli must fall back to Karatsuba.
+        type Z1000 = QuotientRing<i64, StandardOps, IntMultiples<1000>>;
+        let a: Vec<Z1000> = (0..300).map(|_| Z1000::from(pseudo_random(&mut seed, 1000))).collect();
+        let b: Vec<Z1000> = (0..500).map(|_| Z1000::from(pseudo_random(&mut seed, 1000))).collect();
+        let expected = naive_product(&a, &b);
+        let product = Polynomial { coefficients: a, o: PhantomData } * Polynomial { coefficients: b, o: PhantomData };
+        assert!(product.coefficients == expected);
+        type Z999 = QuotientRing<i64, StandardOps, IntMultiples<999>>;
+        let a: Vec<Z999> = (0..400).map(|_| Z999::from(pseudo_random(&mut seed, 999))).collect();
+        let b: Vec<Z999> = (0..400).map(|_| Z999::from(pseudo_random(&mut seed, 999))).collect();
+        let expected = naive_product(&a, &b);
+        let product = Polynomial { coefficients: a, o: PhantomData } * Polynomial { coefficients: b, o: PhantomData };
+        assert!(product.coefficients == expected);
+        // Here 2 and 3 are units, so this goes through Toom-3.
+        let a: Vec<i64> = (0..400).map(|_| pseudo_random(&mut seed, 1000003)).collect();
+        let b: Vec<i64> = (0..401).map(|_| pseudo_random(&mut seed, 1000003)).collect();
+        let expected = poly_mod::<1000003>(naive_product(&a, &b));
+        assert!(poly_mod::<1000003>(a) * poly_mod::<1000003>(b) == expected);
+    }
+
+    #[test]
+    fn number_theoretic_transform() {
+        const P: i64 = 998244353;
+        assert_eq!(ntt::two_adicity::<P>(), 23);
+        let w = ntt::root_of_unity::<P>(1 << 23).unwrap();
+        assert!(Ring::pow(w.clone(), 1 << 23) == IntegersMod::one());
+        assert!(Ring::pow(w, 1 << 22) != IntegersMod::one());
+        assert!(ntt::root_of_unity::<P>(1 << 24).is_none());
+        let mut seed = 7;
+        let a: Vec<IntegersMod<P>> = (0..256).map(|_| IntegersMod::from(pseudo_random(&mut seed, P))).collect();
+        assert!(ntt::inverse_ntt(&ntt::ntt(&a)) == a);
+        // Multiplication uses the transform mod P, and falls back when there is none: 2^8 does not
+        // divide 97-1, and 2^20+1 is not prime.
+        let f: Vec<i64> = (0..300).map(|_| pseudo_random(&mut seed, P)).collect();
+        let g: Vec<i64> = (0..517).map(|_| pseudo_random(&mut seed, P)).collect();
+        let expected: Vec<IntegersMod<P>> = naive_product(&poly_mod::<P>(f.clone()).coefficients, &poly_mod::<P>(g.clone()).coefficients);
+        assert!(ntt::ntt_product(&f, &g, P).is_some());
+        assert!((poly_mod::<P>(f) * poly_mod::<P>(g)).coefficients == expected);
+        let f = poly_mod::<97>((0..100).map(|_| pseudo_random(&mut seed, 97)).collect());
+        assert!((f.clone() * f.clone()).coefficients == naive_product(&f.coefficients, &f.coefficients));
+        type Z = QuotientRing<i64, StandardOps, IntMultiples<{ (1 << 20) + 1 }>>;
+        let f: Vec<Z> = (0..100).map(|_| Z::from(pseudo_random(&mut seed, 1 << 20))).collect();
+        let product = Polynomial { coefficients: f.clone(), o: PhantomData } * Polynomial { coefficients: f.clone(), o: PhantomData };
+        assert!(product.coefficients == naive_product(&f, &f));
+        assert!(ntt::ntt_product(&[1; 100], &[1; 100], (1 << 20) + 1).is_none());
+    }
+
+    #[test]
+    fn pseudo_division() {
+        let f = poly(vec![1, -3, 0, 5, 2]);
+        let g = poly(vec![-1, 0, 3]);
+        let (q, r) = f.pseudo_divide(&g);
+        assert!(r.degree() < 2);
+        assert!(poly(vec![27]) * f.clone() == q.clone() * g.clone() + r.clone());
+        assert!(f.pquo(&g) == q && f.prem(&g) == r);
+        let (q, r) = g.pseudo_divide(&f);
+        assert!(q == poly(vec![]) && r == g);
+    }
+
+    #[test]
+    fn non_monic_integer_ideal() {
+        struct TwoXPlusOne;
+        impl crate::wrapper::Wrapper<Polynomial<i64, StandardOps>> for TwoXPlusOne {
+            const VAL: fn() -> Polynomial<i64, StandardOps> = || poly(vec![1, 2]);
+        }
+        type I = crate::modular::Multiples<Polynomial<i64, StandardOps>, crate::polynomial::PolyOps<i64, StandardOps>, TwoXPlusOne>;
+        assert!(I::contains(&poly(vec![-3, -5, 2])));
+        assert!(I::contains(&poly(vec![2, 4])));
+        assert!(I::contains(&poly(vec![])));
+        assert!(!I::contains(&poly(vec![1, 1])));
+        assert!(!I::contains(&poly(vec![0, 0, 1])));
+        assert!(!I::contains(&poly(vec![1])));
+        type R = QuotientRing<Polynomial<i64, StandardOps>, crate::polynomial::PolyOps<i64, StandardOps>, I>;
+        assert!(R::from(poly(vec![0, 0, 2])) == R::from(poly(vec![0, -1])));
+        assert!(R::from(poly(vec![0, 1])) != R::from(poly(vec![1])));
+    }
+
+    fn big_poly(coefficients: Vec<i64>) -> Polynomial<BigInt, StandardOps> {
+        Polynomial::from_coefficients(coefficients.into_iter().map(BigInt::from).collect())
+    }
+
+    #[test]
+    fn subresultant_gcd() {
+        let f = poly(vec![-12, 18, -12, 18]);
+        assert_eq!(f.content(), 6);
+        assert!(f.primitive_part() == poly(vec![-2, 3, -2, 3]));
+        assert_eq!(poly(vec![4, 0, -6]).content(), -2);
+        let common = poly(vec![1, 0, 1]);
+        let f = common.clone() * poly(vec![-12, 18]);
+        let g = common.clone() * poly(vec![20, 4]);
+        assert!(Polynomial::subresultant_gcd(&f, &g) == common.clone() * poly(vec![2]));
+        assert!(Polynomial::subresultant_gcd(&g, &f) == common.clone() * poly(vec![2]));
+        assert!(Polynomial::subresultant_gcd(&poly(vec![]), &g) == g);
+        let f = poly(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+        let g = poly(vec![21, -9, -4, 0, 5, 0, 3]);
+        assert!(Polynomial::subresultant_gcd(&f, &g) == poly(vec![1]));
+        let common = big_poly(vec![7, -1, 0, 3]);
+        let f = common.clone() * big_poly(vec![1, 1, 0, 0, -4, 9]);
+        let g = common.clone() * big_poly(vec![-3, 0, 2, 11]);
+        assert!(Polynomial::subresultant_gcd(&f, &g) == common);
+        let f = poly_mod::<13>(vec![1, 5, 0, 2]) * poly_mod::<13>(vec![4, 1, 7]);
+        let g = poly_mod::<13>(vec![1, 5, 0, 2]) * poly_mod::<13>(vec![3, 3]);
+        assert!(Polynomial::subresultant_gcd(&f, &g) == EuclideanRing::gcd(f, g).normalized());
+    }
+
+    #[test]
+    fn resultant_and_discriminant() {
+        let f = poly(vec![1, 0, 1]);
+        assert_eq!(Polynomial::resultant(&f, &poly(vec![-2, 1])), 5);
+        assert_eq!(Polynomial::sylvester_resultant(&f, &poly(vec![-2, 1])), 5);
+        assert_eq!(Polynomial::resultant(&poly(vec![-1, 0, 1]), &poly(vec![-1, 1])), 0);
+        assert_eq!(Polynomial::resultant(&poly(vec![3]), &f), 9);
+        assert_eq!(Polynomial::resultant(&f, &poly(vec![])), 0);
+        assert_eq!(f.discriminant(), -4);
+        assert_eq!(poly(vec![5, -3, 2]).discriminant(), 9 - 40);
+        assert_eq!(poly(vec![-5, 2, 0, 1]).discriminant(), -4 * 8 - 27 * 25);
+        assert_eq!(poly(vec![4, -4, 1]).discriminant(), 0);
+        let mut seed = 3;
+        for (m, n) in [(5, 3), (3, 5), (4, 4), (6, 1), (7, 6)] {
+            let f = poly((0..=m).map(|_| pseudo_random(&mut seed, 11) - 5).collect());
+            let g = poly((0..=n).map(|_| pseudo_random(&mut seed, 11) - 5).collect());
+            if m + n < 10 {
+                assert_eq!(Polynomial::resultant(&f, &g), Polynomial::sylvester_resultant(&f, &g));
+            }
+            let fb = big_poly(f.coefficients.clone());
+            let gb = big_poly(g.coefficients.clone());
+            assert_eq!(Polynomial::resultant(&fb, &gb), Polynomial::sylvester_resultant(&fb, &gb));
+            let fp = poly_mod::<101>(f.coefficients.clone());
+            let gp = poly_mod::<101>(g.coefficients.clone());
+            assert!(Polynomial::resultant(&fp, &gp) == Polynomial::sylvester_resultant(&fp, &gp));
+        }
+        let common = poly(vec![2, 1]);
+        assert_eq!(Polynomial::resultant(&(common.clone() * poly(vec![1, 0, 3])), &(common * poly(vec![7, 1]))), 0);
+    }
+
+    #[test]
+    fn square_free_decomposition() {
+        let a = poly_mod::<101>(vec![1, 1]);
+        let b = poly_mod::<101>(vec![3, 1]);
+        let c = poly_mod::<101>(vec![1, 0, 1]);
+        let f = poly_mod::<101>(vec![5]) * a.clone() * Ring::pow(b.clone(), 2) * Ring::pow(c.clone(), 3);
+        assert!(f.yun_square_free() == vec![(a.clone(), 1), (b.clone(), 2), (c.clone(), 3)]);
+        assert!(f.square_free_decomposition() == vec![(a, 1), (b, 2), (c, 3)]);
+
+        let a = poly_mod::<7>(vec![1, 1]) * poly_mod::<7>(vec![3, 1]);
+        let b = poly_mod::<7>(vec![2, 1]);
+        let c = poly_mod::<7>(vec![1, 0, 1]);
+        let d = poly_mod::<7>(vec![5, 1]);
+        let f = a.clone() * Ring::pow(b.clone(), 2) * Ring::pow(c.clone(), 7) * Ring::pow(d.clone(), 8)
+            * Ring::pow(poly_mod::<7>(vec![0, 1]), 15);
+        let decomposition = f.square_free_decomposition();
+        assert!(decomposition == vec![(a, 1), (b, 2), (c.clone(), 7), (d, 8), (poly_mod::<7>(vec![0, 1]), 15)]);
+        let product = decomposition
+            .into_iter()
+            .fold(Polynomial::one(), |p, (g, m)| p * Ring::pow(g, m as u64));
+        assert!(product == f);
+        assert!(Ring::pow(c.clone(), 7).pth_root() == c);
+    }
+
+    fn expand<const P: i64>(
+        (lc, factors): &crate::factor::Factorization<IntegersMod<P>, StandardOps>,
+    ) -> Polynomial<IntegersMod<P>, StandardOps> {
+        factors.iter().fold(Polynomial::constant(lc.clone()), |p, (g, m)| p * Ring::pow(g.clone(), *m as u64))
+    }
+
+    #[test]
+    fn finite_field_factorization() {
+        let f = Ring::pow(poly_mod::<2>(vec![0, 1]), 8).minus(&poly_mod::<2>(vec![0, 1]));
+        let expected = vec![
+            (poly_mod::<2>(vec![0, 1]), 1),
+            (poly_mod::<2>(vec![1, 1]), 1),
+            (poly_mod::<2>(vec![1, 0, 1, 1]), 1),
+            (poly_mod::<2>(vec![1, 1, 0, 1]), 1),
+        ];
+        assert!(f.factor().1 == expected);
+        assert!(f.factor_berlekamp().1 == expected);
+
+        let f = Ring::pow(poly_mod::<7>(vec![0, 1]), 7).minus(&poly_mod::<7>(vec![0, 1]));
+        let factors = f.factor().1;
+        assert_eq!(factors.len(), 7);
+        assert!(factors.iter().all(|(g, m)| g.degree() == 1 && *m == 1));
+
+        let g = poly_mod::<7>(vec![3]) * Ring::pow(poly_mod::<7>(vec![1, 0, 1]), 2)
+            * poly_mod::<7>(vec![3, 1, 0, 1]) * Ring::pow(poly_mod::<7>(vec![6, 1]), 7)
+            * poly_mod::<7>(vec![2, 0, 0, 0, 1]);
+        let factorization = g.factor();
+        assert!(expand(&factorization) == g);
+        assert!(factorization == g.factor_berlekamp());
+        assert!(factorization.1.iter().all(|(h, _)| h.distinct_degree_factorization().len() == 1));
+
+        let mut seed = 11;
+        let h = poly_mod::<1000003>((0..40).map(|_| pseudo_random(&mut seed, 1000003)).collect::<Vec<_>>());
+        let factorization = h.factor();
+        assert!(expand(&factorization) == h);
+        let distinct = h.distinct_degree_factorization();
+        assert_eq!(distinct.iter().map(|(g, d)| g.degree().unwrap() / d).sum::<usize>(), factorization.1.len());
+    }
+
+    #[test]
+    fn integer_factorization() {
+        let (content, factors) = poly(vec![-1, 0, 0, 0, 1]).factor();
+        assert_eq!(content, 1);
+        assert!(factors == vec![(poly(vec![-1, 1]), 1), (poly(vec![1, 1]), 1), (poly(vec![1, 0, 1]), 1)]);
+
+        let swinnerton_dyer = poly(vec![1, 0, -10, 0, 1]);
+        assert!(swinnerton_dyer.factor() == (1, vec![(swinnerton_dyer.clone(), 1)]));
+
+        let f = poly(vec![-6]) * Ring::pow(poly(vec![1, 2]), 2) * poly(vec![-2, 0, 3])
+            * Ring::pow(poly(vec![1, 1, 0, 1]), 3) * poly(vec![-1, 1]);
+        let (content, factors) = f.factor();
+        assert_eq!(content, -6);
+        assert!(factors == vec![
+            (poly(vec![-1, 1]), 1),
+            (poly(vec![1, 2]), 2),
+            (poly(vec![-2, 0, 3]), 1),
+            (poly(vec![1, 1, 0, 1]), 3),
+        ]);
+
+        let big = big_poly(vec![0, 0, 1]).plus(&Polynomial::constant(Ring::pow(BigInt::from(10), 20)));
+        let f = big.clone() * big_poly(vec![-3, 1]) * big_poly(vec![5, 0, 0, 0, 0, 0, 0, 1]);
+        let (content, factors) = f.factor();
+        assert_eq!(content, BigInt::from(1));
+        assert!(factors == vec![(big_poly(vec![-3, 1]), 1), (big, 1), (big_poly(vec![5, 0, 0, 0, 0, 0, 0, 1]), 1)]);
+
+        // Every odd prime below 100 divides the leading coefficient, or the discriminant.
+        let c: BigInt = (3..100u32).filter(|&p| (2..p).all(|d| p % d != 0)).map(BigInt::from).product();
+        let constant = |c: &BigInt| Polynomial::<BigInt, StandardOps>::constant(c.clone());
+        let f = constant(&c) * big_poly(vec![0, 0, 1]) + big_poly(vec![1]);
+        let (content, factors) = f.factor();
+        assert_eq!(content, BigInt::from(1));
+        assert!(factors == vec![(f, 1)]);
+        let linear = constant(&c) * big_poly(vec![0, 1]) + big_poly(vec![1]);
+        let quadratic = big_poly(vec![0, 0, 1]).minus(&constant(&c));
+        let (_, factors) = (linear.clone() * quadratic.clone()).factor();
+        assert!(factors == vec![(linear, 1), (quadratic, 1)]);
+    }
+
+    #[test]
+    fn irreducibility_and_primitivity() {
+        type F2 = Polynomial<IntegersMod<2>, StandardOps>;
+        type F3 = Polynomial<IntegersMod<3>, StandardOps>;
+        assert!(poly_mod::<7>(vec![1, 0, 1]).is_irreducible());
+        assert!(!poly_mod::<5>(vec![1, 0, 1]).is_irreducible());
+        assert!(!poly_mod::<7>(vec![3]).is_irreducible());
+        assert!(poly_mod::<2>(vec![1, 1, 0, 0, 1]).is_primitive());
+        let pentagon = poly_mod::<2>(vec![1, 1, 1, 1, 1]);
+        assert!(pentagon.is_irreducible() && !pentagon.is_primitive());
+        assert!(!poly_mod::<2>(vec![1, 0, 1]).is_irreducible());
+        assert!(poly_mod::<2>(vec![0, 1]).is_irreducible() && !poly_mod::<2>(vec![0, 1]).is_primitive());
+        // Gauss: 3 and 8 monic irreducibles; phi(p^n - 1) / n = 2 and 4 primitive ones.
+        assert_eq!(F2::irreducible_polynomials(4).count(), 3);
+        assert_eq!(F2::primitive_polynomials(4).count(), 2);
+        assert_eq!(F3::irreducible_polynomials(3).count(), 8);
+        assert_eq!(F3::primitive_polynomials(3).count(), 4);
+        for f in F3::irreducible_polynomials(3) {
+            assert!(f.factor().1 == vec![(f.clone(), 1)]);
+        }
+        let f = F2::random_primitive(32, 7);
+        assert!(f.degree() == 32);
+        assert!(f.is_primitive());
+        let g = Polynomial::<IntegersMod<101>, StandardOps>::random_irreducible(5, 3);
+        assert_eq!(g.berlekamp().len(), 1);
+    }
+
+    #[test]
+    fn multivariate_polynomials() {
+        use crate::multivariate::{GrLex, GrevLex, Lex, Monomial, MonomialOrder, MultiPolynomial};
+        use std::cmp::Ordering::*;
+        assert_eq!(Lex::cmp(&[1, 2, 0], &[0, 3, 4]), Greater);
+        assert_eq!(GrLex::cmp(&[1, 2, 0], &[0, 3, 4]), Less);
+        assert_eq!(GrLex::cmp(&[1, 2, 3], &[3, 2, 1]), Less);
+        assert_eq!(GrevLex::cmp(&[4, 7, 1], &[4, 2, 3]), Greater);
+        assert_eq!(GrevLex::cmp(&[1, 5, 2], &[4, 1, 3]), Greater);
+        assert_eq!(GrevLex::cmp(&[1, 2, 4], &[1, 2, 4]), Equal);
+
+        type Q3 = MultiPolynomial<IntegersMod<101>, StandardOps, 3>;
+        let x = Q3::variable(0);
+        let y = Q3::variable(1);
+        let z = Q3::variable(2);
+        let c = |n: i64| Q3::constant(IntegersMod::from(n));
+        let s = x.clone().plus(&y);
+        let square = Ring::pow(s.clone(), 2);
+        assert!(square == x.clone().times(&x).plus(&c(2).times(&x).times(&y)).plus(&y.clone().times(&y)));
+        assert!(s.clone().times(&x.clone().minus(&y)) == x.clone().times(&x).minus(&y.clone().times(&y)));
+        assert!(s.clone().minus(&s).is_zero());
+        assert_eq!(square.total_degree(), Some(2));
+        let f = x.clone().times(&y).times(&z).plus(&c(3).times(&z)).minus(&c(1));
+        let point = [2, 5, 7].map(IntegersMod::<101>::from);
+        assert!(f.eval(&point) == IntegersMod::from(70 + 21 - 1));
+        assert!(Q3::zero().eval(&point) == IntegersMod::from(0));
+
+        let g = x.clone().plus(&y.clone().times(&y)).plus(&c(4).times(&z));
+        assert!(g.leading_monomial() == Some(Monomial::new([0, 2, 0])));
+        let g = g.with_order::<Lex>();
+        let (m, lc) = g.leading_term().unwrap();
+        assert!(m.exponents == [1, 0, 0] && lc == IntegersMod::from(1));
+        assert!(g.with_order::<GrLex>().leading_monomial().unwrap().exponents == [0, 2, 0]);
+        assert!(Q3::zero().leading_term().is_none() && Q3::zero().leading_coefficient() == IntegersMod::from(0));
+    }
+
+    #[test]
+    fn groebner_bases() {
+        use crate::groebner::{groebner_basis, PolynomialIdeal};
+        use crate::multivariate::{GrLex, Lex, MultiOps, MultiPolynomial};
+        type Q2<M> = MultiPolynomial<IntegersMod<101>, StandardOps, 2, M>;
+        fn c<M: crate::multivariate::MonomialOrder>(n: i64) -> Q2<M> {
+            Q2::constant(IntegersMod::from(n))
+        }
+        // Cox, Little and O'Shea: x^3 - 2xy and x^2y - 2y^2 + x under grlex.
+        let x = Q2::<GrLex>::variable(0);
+        let y = Q2::<GrLex>::variable(1);
+        let f1 = Ring::pow(x.clone(), 3).minus(&c(2).times(&x).times(&y));
+        let f2 = Ring::pow(x.clone(), 2).times(&y).minus(&c(2).times(&y).times(&y)).plus(&x);
+        let basis = groebner_basis(&[f1.clone(), f2.clone()]);
+        let half = IntegersMod::<101>::from(2).reciprocal();
+        let expected = vec![
+            y.clone().times(&y).minus(&Q2::constant(half.clone()).times(&x)),
+            x.clone().times(&y),
+            x.clone().times(&x),
+        ];
+        assert!(basis == expected);
+        assert!(f1.clone().times(&y).plus(&f2.clone().times(&x)).normal_form(&basis).is_zero());
+        assert!(!y.normal_form(&basis).is_zero());
+        // The same ideal from other generators has the same reduced basis.
+        assert!(groebner_basis(&[f2.clone(), f1.clone(), f1.clone().plus(&f2)]) == expected);
+        assert!(groebner_basis::<IntegersMod<101>, StandardOps, 2, GrLex>(&[Q2::zero()]).is_empty());
+        assert!(groebner_basis(&[f1, c(3)]) == vec![c(1)]);
+        // Cyclic-3: check Buchberger's criterion on the result directly.
+        type Q3 = MultiPolynomial<IntegersMod<101>, StandardOps, 3>;
+        let [a, b, d] = [0, 1, 2].map(Q3::variable);
+        let cyclic = [
+            a.clone().plus(&b).plus(&d),
+            a.clone().times(&b).plus(&b.clone().times(&d)).plus(&d.clone().times(&a)),
+            a.clone().times(&b).times(&d).minus(&Q3::one()),
+        ];
+        let basis = groebner_basis(&cyclic);
+        assert!(cyclic.iter().all(|f| f.normal_form(&basis).is_zero()));
+        for f in &basis {
+            for g in &basis {
+                assert!(Q3::s_polynomial(f, g).normal_form(&basis).is_zero());
+            }
+        }
+        assert!(basis.iter().any(|g| g.leading_monomial().unwrap().exponents == [0, 0, 3]));
+
+        // The circle meets the line x = y where y^2 = 1/2; lex eliminates x.
+        struct CircleAndLine;
+        impl crate::wrapper::Wrapper<Vec<Q2<Lex>>> for CircleAndLine {
+            const VAL: fn() -> Vec<Q2<Lex>> = || {
+                let (x, y) = (Q2::<Lex>::variable(0), Q2::<Lex>::variable(1));
+                vec![x.clone().times(&x).plus(&y.clone().times(&y)).minus(&c(1)), x.minus(&y)]
+            };
+        }
+        type I = PolynomialIdeal<IntegersMod<101>, StandardOps, 2, Lex, CircleAndLine>;
+        let (x, y) = (Q2::<Lex>::variable(0), Q2::<Lex>::variable(1));
+        assert!(I::basis() == vec![y.clone().times(&y).minus(&Q2::constant(half.clone())), x.clone().minus(&y)]);
+        type R = QuotientRing<Q2<Lex>, MultiOps<IntegersMod<101>, StandardOps, 2, Lex>, I>;
+        let (xr, yr) = (R::from(x.clone()), R::from(y.clone()));
+        assert!(xr == yr);
+        assert!(xr.clone().times(&xr) == R::from(Q2::constant(half.clone())));
+        // x^4 = 1/4, and 4 * 76 = 1 mod 101.
+        assert!(Ring::pow(xr, 4) == R::from(c(76)) && R::from(c(76)) != R::zero());
+    }
+
+    #[test]
+    fn sparse_polynomials() {
+        use crate::sparse::SparsePolynomial;
+        type S = SparsePolynomial<IntegersMod<7>, StandardOps>;
+        let c = |n: i64| S::constant(IntegersMod::from(n));
+        let f = S::x_pow(1_000_000).plus(&c(1));
+        assert!(f.terms.len() == 2 && f.degree() == 1_000_000);
+        // (x^a + 1)(x^a - 1) = x^2a - 1 without touching the exponents in between.
+        let g = S::x_pow(1_000_000).minus(&c(1));
+        assert!(f.clone().times(&g) == S::x_pow(2_000_000).minus(&c(1)));
+        let (q, r) = S::x_pow(2_000_003).plus(&c(5)).divide(&f);
+        assert!(q.clone().times(&f).plus(&r) == S::x_pow(2_000_003).plus(&c(5)));
+        assert!(r.degree() < 1_000_000 && q.terms.len() == 2);
+        assert!(S::gcd(S::x_pow(6).minus(&c(1)), S::x_pow(4).minus(&c(1))).normalized() == S::x_pow(2).minus(&c(1)));
+        // 3 has order 6 mod 7, and 10^6 = 4 mod 6.
+        assert!(f.of(IntegersMod::from(3)) == IntegersMod::from(81 + 1));
+        assert!(S::zero().of(IntegersMod::from(3)) == IntegersMod::from(0));
+        assert!(f.coefficient(1_000_000) == IntegersMod::from(1) && f.coefficient(17) == IntegersMod::from(0));
+
+        // Agrees with the dense representation.
+        let mut seed = 5;
+        for _ in 0..20 {
+            let a = poly_mod::<7>((0..12).map(|_| pseudo_random(&mut seed, 7)).collect());
+            let b = poly_mod::<7>((0..5).map(|_| pseudo_random(&mut seed, 7)).collect());
+            let (sa, sb) = (S::from(a.clone()), S::from(b.clone()));
+            assert!(Polynomial::from(sa.clone().times(&sb)) == a.clone().times(&b));
+            assert!(Polynomial::from(sa.clone().minus(&sb)) == a.clone().minus(&b));
+            if b != Polynomial::zero() {
+                let (q, r) = sa.divide(&sb);
+                let (dq, dr) = Polynomial::divide(a, &b);
+                assert!(Polynomial::from(q) == dq && Polynomial::from(r) == dr);
+            }
+        }
+        assert!(Polynomial::from(S::zero()) == Polynomial::zero());
+    }
+
+    #[test]
+    fn evaluation_and_interpolation() {
+        type F = IntegersMod<10007>;
+        let f = poly(vec![3, -2, 0, 5]);
+        assert_eq!(f.of(2), 3 - 4 + 40);
+        assert_eq!(Polynomial::<i64, StandardOps>::zero().of(2), 0);
+
+        let mut seed = 11;
+        let f = poly_mod::<10007>((0..40).map(|_| pseudo_random(&mut seed, 10007)).collect());
+        let points: Vec<F> = (0..57).map(|_| F::from(pseudo_random(&mut seed, 10007))).collect();
+        let values = f.evaluate_many(&points);
+        assert!(values == points.iter().map(|x| f.of(x.clone())).collect::<Vec<_>>());
+        assert!(f.evaluate_many(&[]).is_empty());
+
+        // Distinct points, and samples of f at 40 of them determine it.
+        let samples: Vec<(F, F)> = (1..=40).map(|i| (F::from(i * i + 3), f.of(F::from(i * i + 3)))).collect();
+        assert!(Polynomial::interpolate(&samples) == f);
+        assert!(Polynomial::lagrange(&samples) == f);
+        assert!(Polynomial::newton(&samples) == f);
+        assert!(Polynomial::<F, StandardOps>::interpolate(&[]) == Polynomial::zero());
+        let single = [(F::from(4), F::from(9))];
+        assert!(Polynomial::interpolate(&single) == poly_mod::<10007>(vec![9]));
+        assert!(Polynomial::newton(&single) == poly_mod::<10007>(vec![9]));
+
+        // Shamir secret sharing with threshold 3: any three shares recover the secret f(0).
+        let dealer = poly_mod::<10007>(vec![1234, 166, 94]);
+        let shares: Vec<(F, F)> = (1..=5).map(|i| (F::from(i), dealer.of(F::from(i)))).collect();
+        for subset in [[0, 1, 2], [0, 3, 4], [1, 2, 4]] {
+            let chosen: Vec<(F, F)> = subset.iter().map(|&i| shares[i].clone()).collect();
+            assert!(Polynomial::lagrange(&chosen).of(F::from(0)) == F::from(1234));
+        }
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        use crate::crt::{crt, NotCoprime};
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Ok((23, 105)));
+        assert_eq!(crt(&[-1, 0], &[4, -9]), Ok((27, -36)));
+        assert_eq!(crt::<i64, StandardOps>(&[], &[]), Ok((0, 1)));
+        assert_eq!(crt(&[1, 2, 3], &[5, 6, 10]), Err(NotCoprime { index: 2, gcd: 10 }));
+
+        // Combining results computed modulo several primes.
+        let big = |n: i64| BigInt::from(n);
+        let secret = big(123456789) * big(987654321);
+        let primes = [1000003, 1000033, 1000037, 1000039].map(big);
+        let residues: Vec<BigInt> = primes.iter().map(|p| &secret % p).collect();
+        let (x, m) = crt(&residues, &primes).unwrap();
+        assert!(x == secret && m == primes.iter().product::<BigInt>());
+
+        // Over F_7[x], with moduli x - 3 and x^2 + 1.
+        let f = poly_mod::<7>(vec![1, 1]);
+        let g = poly_mod::<7>(vec![4, 0, 1]);
+        let m1 = poly_mod::<7>(vec![-3, 1]);
+        let m2 = poly_mod::<7>(vec![1, 0, 1]);
+        let Ok((x, m)) = crt(&[f.clone(), g.clone()], &[m1.clone(), m2.clone()]) else { panic!() };
+        assert!(m == m1.clone().times(&m2));
+        assert!(x.clone().remainder(&m1) == f.clone().remainder(&m1) && x.clone().remainder(&m2) == g.clone().remainder(&m2));
+        assert!(x.degree() < 3);
+        let Err(err) = crt(&[f.clone(), g], &[m1.clone(), m1.clone().times(&m2)]) else { panic!() };
+        assert!(err.index == 1 && err.gcd == m1);
+    }
+
+    #[test]
+    fn display_and_parse() {
+        use crate::format::ParsePolynomialError;
+        let f = poly(vec![1, -1, 3]);
+        assert_eq!(f.to_string(), "3x^2 - x + 1");
+        assert_eq!(format!("{:?}", f), "3x^2 - x + 1");
+        assert_eq!(f.display("t").to_string(), "3t^2 - t + 1");
+        assert_eq!(poly(vec![0, -2, 0, -1]).to_string(), "-x^3 - 2x");
+        assert_eq!(poly(vec![-5]).to_string(), "-5");
+        assert_eq!(Polynomial::<i64, StandardOps>::zero().to_string(), "0");
+        assert_eq!(poly_mod::<7>(vec![-1, 0, 1]).to_string(), "x^2 + 6");
+        assert_eq!(IntegersMod::<7>::from(-1).to_string(), "6");
+        assert_eq!(format!("{:?}", IntegersMod::<7>::from(-1)), "[6]");
+        // Coefficients with several terms are parenthesized.
+        let nested = Polynomial::<Polynomial<i64, StandardOps>, crate::polynomial::PolyOps<i64, StandardOps>>::from_coefficients(vec![
+            poly(vec![0, 2]),
+            poly(vec![1, -1]),
+        ]);
+        assert_eq!(nested.display("y").to_string(), "(-x + 1)y + 2x");
+
+        assert!("3x^2 - x + 1".parse::<Polynomial<i64, StandardOps>>() == Ok(f.clone()));
+        assert!("1 - x + 3*x^2".parse() == Ok(f.clone()));
+        assert!(Polynomial::parse_with("3t^2-t+1", "t") == Ok(f.clone()));
+        assert!("x^2 + x^2 - 2x^2 + 0".parse() == Ok(Polynomial::<i64, StandardOps>::zero()));
+        assert!("-x^3 - -2x".parse() == Ok(poly(vec![0, 2, 0, -1])));
+        assert!("(4)x + 8".parse() == Ok(poly_mod::<7>(vec![1, 4])));
+        assert!("123456789012345678901234567890x".parse() == Ok(big_poly(vec![0, 1]).times(&Polynomial::constant("123456789012345678901234567890".parse().unwrap()))));
+        assert!(Polynomial::parse_with("(-x + 1)y + 2x", "y") == Ok(nested));
+        let error = |term: &str| Err(ParsePolynomialError { term: term.to_string() });
+        assert!("3x^2 + y".parse::<Polynomial<i64, StandardOps>>() == error("+y"));
+        assert!("x + ".parse::<Polynomial<i64, StandardOps>>() == error("+"));
+        assert!("x^a".parse::<Polynomial<i64, StandardOps>>() == error("x^a"));
+        assert!("".parse::<Polynomial<i64, StandardOps>>() == error(""));
+
+        // Round trips.
+        let mut seed = 3;
+        for _ in 0..20 {
+            let g = poly((0..6).map(|_| pseudo_random(&mut seed, 9) - 4).collect());
+            assert!(g.to_string().parse() == Ok(g.clone()));
+        }
+    }
+
+    #[test]
+    fn real_root_isolation() {
+        use crate::real_roots::Rational;
+        let q = |n: i64, d: i64| Rational::new(BigInt::from(n), BigInt::from(d));
+        fn is_root(f: &Polynomial<i64, StandardOps>, r: &Rational) -> bool {
+            let mut value = BigInt::from(0);
+            let mut power = BigInt::from(1);
+            for c in f.coefficients.iter().rev() {
+                value = value * &r.numerator + c * &power;
+                power *= &r.denominator;
+            }
+            value == BigInt::from(0)
+        }
+        // Checks that the intervals are sorted, disjoint and each isolate one root.
+        let check = |f: &Polynomial<i64, StandardOps>, expected: usize| {
+            let intervals = f.isolate_real_roots();
+            assert_eq!(intervals.len(), expected);
+            assert_eq!(f.count_real_roots(), expected);
+            for (k, (a, b)) in intervals.iter().enumerate() {
+                assert!(a <= b);
+                if k > 0 {
+                    assert!(intervals[k - 1].1 <= *a);
+                }
+                if a == b {
+                    assert!(is_root(f, a));
+                } else {
+                    let on_boundary = usize::from(is_root(f, b));
+                    assert_eq!(f.count_real_roots_between(a, b), 1 + on_boundary);
+                }
+            }
+            intervals
+        };
+        assert_eq!(q(6, -4), q(-3, 2));
+        assert!(q(-3, 2) < q(-1, 1) && q(2, 3) > q(3, 5));
+        assert_eq!(q(6, -4).to_string(), "-3/2");
+
+        let sqrt2 = check(&poly(vec![-2, 0, 1]), 2);
+        assert!(sqrt2[0].1 <= q(0, 1) && sqrt2[1].0 >= q(0, 1));
+        // Repeated and complex roots are not counted twice or at all.
+        check(&poly(vec![1, -2, 1]).times(&poly(vec![3, 1])).times(&poly(vec![1, 0, 1])), 2);
+        let ten = (1..=10).fold(poly(vec![1]), |f, i| f.times(&poly(vec![-i, 1])));
+        let roots = check(&ten, 10);
+        for (i, (a, b)) in roots.iter().enumerate() {
+            let r = q(i as i64 + 1, 1);
+            assert!(*a <= r && r <= *b);
+        }
+        check(&poly(vec![-1, -1, 0, 0, 0, 1]), 1);
+        check(&poly(vec![0, -1, 0, 1]), 3);
+        // Chebyshev T_8 has eight roots in (-1, 1).
+        let t8 = poly(vec![1, 0, -32, 0, 160, 0, -256, 0, 128]);
+        check(&t8, 8);
+        // Roots 1/1001 and 1/1000 are close together but still separated.
+        let close = poly(vec![-1, 1000]).times(&poly(vec![-1, 1001]));
+        let intervals = check(&close, 2);
+        assert!(intervals[0].1 < q(1, 1000) && q(1, 1001) < intervals[1].0);
+        check(&poly(vec![5]), 0);
+        check(&poly(vec![1, 0, 1]), 0);
+
+        assert_eq!(poly(vec![0, -1, 0, 1]).count_real_roots_between(&q(-1, 1), &q(1, 1)), 2);
+        assert_eq!(poly(vec![0, -1, 0, 1]).count_real_roots_between(&q(-2, 1), &q(-1, 2)), 1);
+        assert_eq!(poly(vec![0, -1, 0, 1]).count_real_roots_between(&q(1, 1), &q(-1, 1)), 0);
+        let sturm = big_poly(vec![-2, 0, 1]).sturm_sequence();
+        assert!(sturm == vec![big_poly(vec![-2, 0, 1]), big_poly(vec![0, 2]), big_poly(vec![1])]);
+    }
+
+    #[test]
+    fn laurent_polynomials() {
+        use crate::laurent::LaurentPolynomial;
+        use crate::unit::TryInverse;
+        type L = LaurentPolynomial<i64, StandardOps>;
+        let t = L::x();
+        let t_inv = L::x_inverse();
+        assert!(t.clone().times(&t_inv) == L::one());
+        assert!(t_inv.degree() == Some(-1) && t_inv.valuation() == Some(-1));
+        assert!(L::zero().degree().is_none() && L::zero().valuation().is_none());
+        // The Jones polynomial of the trefoil, -t^-4 + t^-3 + t^-1, and of its mirror image.
+        let jones = L::new(-4, vec![-1, 1, 0, 1]);
+        let mirror = jones.substitute_x_inverse();
+        assert!(mirror == L::new(1, vec![1, 0, 1, -1]));
+        assert!(mirror.substitute_x_inverse() == jones);
+        assert!(jones.coefficient(-4) == -1 && jones.coefficient(-2) == 0 && jones.coefficient(5) == 0);
+        assert!(jones.of(1) == Some(1));
+        assert!(jones.of(2).is_none() && mirror.of(2) == Some(2 + 8 - 16));
+        // (t + t^-1)^2 = t^2 + 2 + t^-2
+        let s = t.clone().plus(&t_inv);
+        assert!(Ring::pow(s.clone(), 2) == L::new(-2, vec![1, 0, 2, 0, 1]));
+        assert!(s.clone().minus(&s) == L::zero());
+        assert!(L::new(3, vec![0, 0, 5, 0]) == L::monomial(5, 5));
+
+        let f = poly(vec![0, 0, 1, 2]);
+        let lf = L::from(f.clone());
+        assert!(lf.valuation == 2 && lf.to_polynomial() == Some(f.clone()));
+        assert!(lf.clone().multiply_by_x_pow(-3) == L::new(-1, vec![1, 2]));
+        assert!(lf.clone().multiply_by_x_pow(-3).to_polynomial().is_none());
+        assert!(L::zero().multiply_by_x_pow(-3) == L::zero());
+        assert!(L::from(poly(vec![1, 2])).times(&t_inv).plus(&L::constant(-2)) == t_inv);
+
+        assert!(L::monomial(-1, 3).try_inverse() == Some(L::monomial(-1, -3)));
+        assert!(L::monomial(2, 3).try_inverse().is_none());
+        assert!(s.try_inverse().is_none());
+    }
+
+    #[test]
+    fn power_series() {
+        use crate::power_series::PowerSeries;
+        use crate::unit::TryInverse;
+        type Z10 = PowerSeries<i64, StandardOps, 10>;
+        let x = Z10::x();
+        // Fibonacci numbers from 1 / (1 - x - x^2), and the precision cuts off x^10.
+        let fibonacci = Z10::from_coefficients(vec![1, -1, -1]).reciprocal().unwrap();
+        assert!(fibonacci.coefficients == vec![1, 1, 2, 3, 5, 8, 13, 21, 34, 55]);
+        assert!(fibonacci.clone().times(&Z10::from_coefficients(vec![1, -1, -1])) == Z10::one());
+        assert!(Z10::from_coefficients(vec![2, 1]).reciprocal().is_none());
+        assert!(Z10::from_coefficients(vec![-1, 1]).try_inverse() == Some(Z10::from_coefficients(vec![-1; 10])));
+        // Partitions: the product of 1 / (1 - x^k).
+        let partitions = (1..10).fold(Z10::one(), |p, k| p.times(&Z10::one().minus(&Ring::pow(x.clone(), k)).reciprocal().unwrap()));
+        assert!(partitions.coefficients == vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30]);
+        assert!(Ring::pow(x.clone(), 10) == Z10::zero());
+        assert!(Z10::from(poly(vec![1, 2, 3])).to_polynomial() == poly(vec![1, 2, 3]));
+
+        type F = IntegersMod<1000003>;
+        type S = PowerSeries<F, StandardOps, 12>;
+        let f = |v: Vec<i64>| S::from_coefficients(v.into_iter().map(F::from).collect());
+        // exp(x) = sum x^k / k!
+        let e = S::x().exp();
+        let mut factorial = F::from(1);
+        for k in 0..12 {
+            assert!(e.coefficient(k).times(&factorial) == F::from(1));
+            factorial = factorial.times(&F::from(k as i64 + 1));
+        }
+        // log(1 - x) = -sum x^k / k
+        let l = f(vec![1, -1]).log();
+        for k in 1..12 {
+            assert!(l.coefficient(k).times(&F::from(k as i64)) == F::from(-1));
+        }
+        let g = f(vec![1, 3, 0, -7, 2, 5]);
+        assert!(g.log().exp() == g);
+        let h = f(vec![0, 4, 1, 0, 9]);
+        assert!(h.exp().log() == h);
+        assert!(g.sqrt().times(&g.sqrt()) == g);
+        assert!(h.exp().times(&h.clone().negated().exp()) == S::one());
+        assert!(g.pow_field(&F::from(3)) == Ring::pow(g.clone(), 3));
+        // Catalan numbers: (1 - sqrt(1 - 4x)) / 2x.
+        let root = f(vec![1, -4]).sqrt();
+        let catalan: Vec<F> = root.coefficients[1..].iter().map(|c| c.clone().times(&F::from(-2).reciprocal())).collect();
+        let expected = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796].map(F::from);
+        assert!(catalan == expected);
+        assert!(S::x().integral().derivative() == S::x());
+    }
+
+    #[test]
+    fn cyclotomic_polynomials() {
+        use crate::cyclotomic::{cyclotomic, CyclotomicRing, EisensteinIntegers, GaussianIntegers};
+        assert!(cyclotomic(1) == poly(vec![-1, 1]));
+        assert!(cyclotomic(2) == poly(vec![1, 1]));
+        assert!(cyclotomic(4) == poly(vec![1, 0, 1]));
+        assert!(cyclotomic(6) == poly(vec![1, -1, 1]));
+        assert!(cyclotomic(12) == poly(vec![1, 0, -1, 0, 1]));
+        assert!(cyclotomic(7) == poly(vec![1; 7]));
+        // The first with a coefficient other than 0 and ±1.
+        assert!(cyclotomic(105).coefficients.contains(&-2));
+        let n = 30;
+        let product = (1..=n).filter(|d| n % d == 0).fold(poly(vec![1]), |p, d| p * cyclotomic(d));
+        assert!(product == Polynomial::x_pow(n) + poly(vec![-1]));
+
+        let i = GaussianIntegers::zeta();
+        assert!(i.clone().times(&i) == GaussianIntegers::from(poly(vec![-1])));
+        let one_plus_i = GaussianIntegers::from(poly(vec![1, 1]));
+        let one_minus_i = GaussianIntegers::from(poly(vec![1, -1]));
+        assert!(one_plus_i.times(&one_minus_i) == GaussianIntegers::from(poly(vec![2])));
+        assert_eq!(Ring::pow(GaussianIntegers::from(poly(vec![2, 1])), 2).to_string(), "4x + 3");
+
+        let w = EisensteinIntegers::zeta();
+        assert!(Ring::pow(w.clone(), 3) == EisensteinIntegers::one());
+        assert!(EisensteinIntegers::one().plus(&w).plus(&w.clone().times(&w)) == EisensteinIntegers::zero());
+
+        let z = CyclotomicRing::<12>::zeta();
+        assert!(Ring::pow(z.clone(), 12) == CyclotomicRing::<12>::one());
+        assert!(Ring::pow(z.clone(), 6) != CyclotomicRing::<12>::one());
+        assert!(Ring::pow(z.clone(), 6) == CyclotomicRing::<12>::one().negated());
+        // Representatives are reduced below degree φ(12) = 4.
+        assert_eq!(Ring::pow(z, 5).to_string(), "x^3 - x");
+    }
+
+    #[test]
+    fn polynomial_ideals() {
+        use crate::modular::Multiples;
+        use crate::polynomial::PolyOps;
+        use crate::unit::TryInverse;
+        use crate::wrapper::Wrapper;
+        // GF(7)[x]/(x^2 + 1), the field with 49 elements.
+        type F7X = Polynomial<IntegersMod<7>, StandardOps>;
+        struct XSquaredPlusOne;
+        impl Wrapper<F7X> for XSquaredPlusOne {
+            const VAL: fn() -> F7X = || poly_mod::<7>(vec![1, 0, 1]);
+        }
+        type GF49 = QuotientRing<F7X, PolyOps<IntegersMod<7>, StandardOps>, Multiples<F7X, PolyOps<IntegersMod<7>, StandardOps>, XSquaredPlusOne>>;
+        let i = GF49::from(poly_mod::<7>(vec![0, 1]));
+        assert!(i.clone().times(&i) == GF49::from(poly_mod::<7>(vec![6])));
+        assert!(Ring::pow(i.clone(), 48) == GF49::one());
+        assert_eq!(GF49::from(poly_mod::<7>(vec![3, 0, 2, 1])).to_string(), "6x + 1");
+
+        // (Z/1000)[x]/(x^2 + 1): the coefficients are not a field, but the modulus is monic.
+        type Z1000 = QuotientRing<i64, StandardOps, crate::quotient::IntMultiples<1000>>;
+        type Z1000X = Polynomial<Z1000, StandardOps>;
+        struct Z1000XSquaredPlusOne;
+        impl Wrapper<Z1000X> for Z1000XSquaredPlusOne {
+            const VAL: fn() -> Z1000X = || Z1000X::from_coefficients(vec![Z1000::one(), Z1000::zero(), Z1000::one()]);
+        }
+        type GaussianZ1000 = QuotientRing<Z1000X, PolyOps<Z1000, StandardOps>, Multiples<Z1000X, PolyOps<Z1000, StandardOps>, Z1000XSquaredPlusOne>>;
+        let z1000 = |c: Vec<i64>| Z1000X::from_coefficients(c.into_iter().map(Z1000::from).collect());
+        let i = GaussianZ1000::from(z1000(vec![0, 1]));
+        assert!(i.clone().times(&i) == GaussianZ1000::from(z1000(vec![999])));
+        assert!(GaussianZ1000::from(z1000(vec![7, 3, 2])) == GaussianZ1000::from(z1000(vec![5, 3])));
+        assert!(Ring::pow(GaussianZ1000::from(z1000(vec![1, 1])), 4) == GaussianZ1000::from(z1000(vec![996])));
+        assert!(Z1000::from(3).try_inverse() == Some(Z1000::from(667)));
+        assert!(Z1000::from(4).try_inverse().is_none());
+
+        // BigInt[x]/(x^2 - 2), and a modulus that is not monic.
+        type ZX = Polynomial<BigInt, StandardOps>;
+        struct XSquaredMinusTwo;
+        impl Wrapper<ZX> for XSquaredMinusTwo {
+            const VAL: fn() -> ZX = || big_poly(vec![-2, 0, 1]);
+        }
+        type RootTwo = QuotientRing<ZX, PolyOps<BigInt, StandardOps>, Multiples<ZX, PolyOps<BigInt, StandardOps>, XSquaredMinusTwo>>;
+        let s = RootTwo::from(big_poly(vec![1, 1]));
+        assert_eq!(Ring::pow(s, 4).to_string(), "12x + 17");
+        struct TwoXPlusOne;
+        impl Wrapper<ZX> for TwoXPlusOne {
+            const VAL: fn() -> ZX = || big_poly(vec![1, 2]);
+        }
+        type I = Multiples<ZX, PolyOps<BigInt, StandardOps>, TwoXPlusOne>;
+        assert!(I::contains(&big_poly(vec![-3, -5, 2])));
+        assert!(!I::contains(&big_poly(vec![0, 0, 1])));
+        assert!(!I::contains(&big_poly(vec![1])));
+
+        // F_5[y][x]/(yx - 1): the leading coefficient y is not a unit, so membership goes
+        // through Gauss's lemma over the Euclidean ring F_5[y].
+        type Y = Polynomial<IntegersMod<5>, StandardOps>;
+        type YX = Polynomial<Y, PolyOps<IntegersMod<5>, StandardOps>>;
+        struct YXMinusOne;
+        impl Wrapper<YX> for YXMinusOne {
+            const VAL: fn() -> YX = || YX::from_coefficients(vec![poly_mod::<5>(vec![4]), poly_mod::<5>(vec![0, 1])]);
+        }
+        type J = Multiples<YX, PolyOps<Y, PolyOps<IntegersMod<5>, StandardOps>>, YXMinusOne>;
+        let g = YXMinusOne::VAL();
+        let h = YX::from_coefficients(vec![poly_mod::<5>(vec![1, 0, 2]), Y::one()]);
+        assert!(J::contains(&g.clone().times(&h)));
+        assert!(!J::contains(&YX::x()));
+        assert!(!J::contains(&YX::constant(poly_mod::<5>(vec![0, 1]))));
+
+        // Sparse polynomials over a field keep their quotient rings.
+        type S = crate::sparse::SparsePolynomial<IntegersMod<5>, StandardOps>;
+        struct XCubedPlusOne;
+        impl Wrapper<S> for XCubedPlusOne {
+            const VAL: fn() -> S = || S::x_pow(3).plus(&S::one());
+        }
+        type K = Multiples<S, crate::sparse::SparseOps<IntegersMod<5>, StandardOps>, XCubedPlusOne>;
+        assert!(K::contains(&S::x_pow(6).minus(&S::one())));
+        assert!(!K::contains(&S::x_pow(6).plus(&S::one())));
+        assert!(<K as crate::structure::ring::Ideal<_, _>>::reduce(S::x_pow(4)) == S::x().negated());
+    }
+
+    #[test]
+    fn newton_division() {
+        use crate::division::PreparedDivisor;
+        use crate::modular::Multiples;
+        use crate::polynomial::PolyOps;
+        use crate::wrapper::Wrapper;
+        const P: i64 = 998244353;
+        let mut seed = 17;
+        let mut random = |n: usize| poly_mod::<P>((0..n).map(|_| pseudo_random(&mut seed, P)).collect());
+        // Both the divisor and the quotient are long enough for Newton inversion.
+        for (m, n) in [(400, 150), (129, 64), (300, 299), (100, 130), (1000, 10)] {
+            let f = random(m);
+            let g = random(n).plus(&Polynomial::x_pow(n));
+            let (q, r) = Polynomial::divide(f.clone(), &g);
+            assert!(r.degree() < g.degree());
+            assert!(q.times(&g).plus(&r) == f);
+        }
+        // Monic over the integers, where the inverse of the reversal stays integral.
+        let g = Polynomial::x_pow(70) + big_poly((0..70).map(|i| i % 5 - 2).collect());
+        let f = big_poly((0..200).map(|i| (i * i) % 11 - 5).collect());
+        let (q, r) = Polynomial::divide(f.clone(), &g);
+        assert!(r.degree() < g.degree());
+        assert!(q.times(&g).plus(&r) == f);
+        // A prepared divisor extends its precision for long quotients.
+        let g = random(20).plus(&Polynomial::x_pow(20));
+        let prepared = PreparedDivisor::new(g.clone(), 5);
+        assert_eq!(prepared.precision(), 5);
+        for m in [3, 20, 24, 60] {
+            let f = random(m);
+            let (q, r) = prepared.divide(f.clone());
+            assert!(r.degree() < g.degree());
+            assert!(q.times(&g).plus(&r) == f);
+        }
+        // Reduction in a quotient ring by a large modulus, with the inverse cached.
+        type FX = Polynomial<IntegersMod<P>, StandardOps>;
+        struct Modulus;
+        impl Wrapper<FX> for Modulus {
+            const VAL: fn() -> FX = || {
+                let mut seed = 5;
+                let mut c: Vec<_> = (0..100).map(|_| IntegersMod::<P>::from(pseudo_random(&mut seed, P))).collect();
+                c.push(IntegersMod::from(3));
+                Polynomial::from_coefficients(c)
+            };
+        }
+        type R = QuotientRing<FX, PolyOps<IntegersMod<P>, StandardOps>, Multiples<FX, PolyOps<IntegersMod<P>, StandardOps>, Modulus>>;
+        let x = R::from(Polynomial::x());
+        let by_powers = Ring::pow(x, 1000);
+        let (_, expected) = Polynomial::divide(Polynomial::x_pow(1000), &Modulus::VAL());
+        assert!(by_powers == R::from(expected));
+    }
+
+    #[test]
+    fn half_gcd() {
+        // Classical Euclid, which the half-GCD must agree with exactly.
+        type FX<const P: i64> = Polynomial<IntegersMod<P>, StandardOps>;
+        fn classical<const P: i64>(a: FX<P>, b: FX<P>) -> [FX<P>; 3] {
+            let (mut r0, mut r1) = (a, b);
+            let (mut x0, mut x1) = (Polynomial::one(), Polynomial::zero());
+            let (mut y0, mut y1) = (Polynomial::zero(), Polynomial::one());
+            while r1 != Polynomial::zero() {
+                let (q, r) = Polynomial::divide(r0, &r1);
+                (r0, r1) = (r1, r);
+                (x0, x1) = (x1.clone(), x0.minus(&q.clone().times(&x1)));
+                (y0, y1) = (y1.clone(), y0.minus(&q.times(&y1)));
+            }
+            [r0, x0, y0]
+        }
+        fn check<const P: i64>(seed: &mut u64, m: usize, n: usize, common: usize) {
+            let mut random = |k: usize| poly_mod::<P>((0..k).map(|_| pseudo_random(seed, P)).collect());
+            let g = random(common + 1);
+            let a = random(m).times(&g);
+            let b = random(n).times(&g);
+            let [d, x, y] = classical(a.clone(), b.clone());
+            assert!(EuclideanRing::gcd(a.clone(), b.clone()) == d);
+            let (x2, y2) = EuclideanRing::bézout(a.clone(), b.clone());
+            assert!(x2 == x && y2 == y);
+            assert!(a.times(&x2).plus(&b.times(&y2)) == d);
+        }
+        let mut seed = 3;
+        for (m, n, common) in [(300, 250, 0), (400, 400, 50), (150, 500, 100), (700, 699, 3), (10, 20, 5), (260, 130, 130)] {
+            check::<998244353>(&mut seed, m, n, common);
+        }
+        // Over GF(2) the degrees of the remainders often drop by more than one.
+        for (m, n, common) in [(500, 480, 20), (300, 301, 0), (200, 100, 64)] {
+            check::<2>(&mut seed, m, n, common);
+        }
+        // The half-GCD stops at the first remainder of degree below half.
+        let mut random = |k: usize| poly_mod::<101>((0..k).map(|_| pseudo_random(&mut seed, 101)).collect());
+        let a = random(200).plus(&Polynomial::x_pow(200));
+        let b = random(180);
+        let m = Polynomial::half_gcd(&a, &b);
+        let c = m[0][0].clone().times(&a).plus(&m[0][1].clone().times(&b));
+        let d = m[1][0].clone().times(&a).plus(&m[1][1].clone().times(&b));
+        assert!(c.degree() >= 100 && d.degree() < 100);
+        assert!(EuclideanRing::gcd(c, d).normalized() == EuclideanRing::gcd(a, b).normalized());
+    }
+
+    #[test]
+    fn rational_functions() {
+        use crate::power_series::PowerSeries;
+        use crate::rational_function::RationalFunction;
+        type F = IntegersMod<101>;
+        type RF = RationalFunction<F, StandardOps>;
+        let p = poly_mod::<101>;
+        let r = |n: Vec<i64>, d: Vec<i64>| RF::new(p(n), p(d));
+        // (x^2 - 1) / (2x - 2) = (x + 1) / 2, with a monic denominator.
+        let f = r(vec![-1, 0, 1], vec![-2, 2]);
+        assert!(f.denominator() == &p(vec![1]));
+        assert!(f.numerator() == &p(vec![51, 51]));
+        assert!(f == RF::constant(F::from(2)).reciprocal().times(&RF::from(p(vec![1, 1]))));
+        assert!(r(vec![0], vec![3, 4]) == RF::zero());
+        // Field operations.
+        let g = r(vec![1, 2], vec![3, 0, 1]);
+        let h = r(vec![5], vec![1, 1]);
+        assert!(g.clone().times(&g.clone().reciprocal()) == RF::one());
+        assert!(g.clone().plus(&h).minus(&h) == g);
+        assert!(g.clone().plus(&h).times(&h) == g.clone().times(&h).plus(&h.clone().times(&h)));
+        assert!(h.clone().reciprocal() == r(vec![1, 1], vec![5]));
+        assert_eq!(g.to_string(), "(2x + 1)/(x^2 + 3)");
+        assert_eq!(RF::x().to_string(), "x");
+        // Evaluation, with a pole at -1.
+        assert!(h.of(F::from(4)) == Some(F::from(1)));
+        assert!(h.of(F::from(-1)).is_none());
+        assert!(g.clone().plus(&h).of(F::from(2)) == Some(g.of(F::from(2)).unwrap().plus(&h.of(F::from(2)).unwrap())));
+        // d/dx 1/(1 - x) = 1/(1 - x)^2.
+        assert!(r(vec![1], vec![1, -1]).derivative() == r(vec![1], vec![1, -2, 1]));
+        // The Fibonacci generating function x / (1 - x - x^2).
+        let fibonacci: PowerSeries<F, StandardOps, 10> = r(vec![0, 1], vec![1, -1, -1]).expand().unwrap();
+        assert!(fibonacci.coefficients == [0, 1, 1, 2, 3, 5, 8, 13, 21, 34].map(F::from).to_vec());
+        assert!(RF::x().reciprocal().expand::<4>().is_none());
+        // (x^5 + 2x^3 + 3) / ((x - 1)^2 (x^2 + 1)) = x + 2 + a / (x - 1) + b / (x - 1)^2 + c / (x^2 + 1).
+        let q = RF::new(p(vec![3, 0, 0, 2, 0, 1]), p(vec![1, -1]).times(&p(vec![1, -1])).times(&p(vec![1, 0, 1])));
+        let factors = vec![(p(vec![-1, 1]), 2), (p(vec![1, 0, 1]), 1)];
+        let (whole, parts) = q.partial_fractions(&factors);
+        assert!(whole == p(vec![2, 1]));
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 2);
+        // The coefficient of 1 / (x - 1)^2 is the rest of the function at 1: (1 + 2 + 3) / (1 + 1).
+        assert!(parts[0][1] == p(vec![3]));
+        assert!(parts.iter().flatten().zip([1, 1, 2]).all(|(a, d)| a.degree() < d));
+        let mut sum = RF::from(whole);
+        for ((factor, _), numerators) in factors.iter().zip(&parts) {
+            for (j, a) in numerators.iter().enumerate() {
+                sum = sum.plus(&RF::new(a.clone(), Ring::pow(factor.clone(), j as u64 + 1)));
+            }
+        }
+        assert!(sum == q);
+        // With the factorization that factor finds.
+        let d = p(vec![2, 0, 0, 1]).times(&p(vec![1, 1])).times(&p(vec![1, 1]));
+        let q = RF::new(p(vec![7, 0, 1, 5]), d.clone());
+        let (_, factors) = d.factor();
+        let (whole, parts) = q.partial_fractions(&factors);
+        let mut sum = RF::from(whole);
+        for ((factor, _), numerators) in factors.iter().zip(&parts) {
+            for (j, a) in numerators.iter().enumerate() {
+                sum = sum.plus(&RF::new(a.clone(), Ring::pow(factor.clone(), j as u64 + 1)));
+            }
+        }
+        assert!(sum == q);
+        // Polynomials over the rational functions form a Euclidean ring, as over any field.
+        type Rfx = Polynomial<RF, crate::rational_function::RationalOps<F, StandardOps>>;
+        let a = Rfx::from_coefficients(vec![h.clone(), RF::one()]);
+        let b = Rfx::from_coefficients(vec![g.clone(), RF::x()]);
+        let product = a.clone().times(&b);
+        assert!(product.quotient(&a) == b);
+    }
 }
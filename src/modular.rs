@@ -1,10 +1,85 @@
 use std::marker::PhantomData;
 
+use num_bigint::BigInt;
+
 use crate::{
-    structure::{euclidean_ring::{EuclideanRing}, ring::{RingOperations, Ideal, Ring}, monoid::{AbsorbingSubset, Monoid}, group::{Subgroup, Group}},
-    wrapper::{Wrapper}, operation::O2, set::{Set, Subset},
+    structure::{euclidean_ring::{EuclideanRing}, ring::{RingOperations, Ideal, Ring}, monoid::{AbsorbingSubset, Monoid}, group::{Subgroup, Group}, field::Field},
+    wrapper::{Wrapper}, operation::O2, set::{Set, Subset}, nonzero::NonZero, impls::StandardOps, unit::TryInverse,
+    ntt::ntt_product,
 };
 
+/// Rings in which it can be decided whether `g` divides `t`, which is what `Multiples` needs to be
+/// an ideal. Implemented for fields, the integers, and polynomials over any ring with
+/// `TryInverse`, where a modulus that is not monic up to a unit is left to
+/// `TryInverse::polynomial_divides`, e.g. Gauss's lemma over a `EuclideanRing`.
+pub trait Divisibility<O:RingOperations<Self>>: Ring<O> {
+    fn divides(g:&Self, t:&Self) -> bool;
+    //A representative of the class of t mod g. It should be the same for all of the class when
+    //there is a canonical choice, like a remainder; the default only sends multiples of g to zero.
+    fn reduce_modulo(t:Self, g:&Self) -> Self {
+        if Self::divides(g, &t) {
+            Self::zero()
+        } else {
+            t
+        }
+    }
+    //Reduces t mod A::VAL(). Since the modulus is fixed by the type A, implementations may compute
+    //data about it once and cache it, keyed by A, for every later reduction. The cache only holds
+    //'static types, hence the bound.
+    fn reduce_modulo_fixed<A:Wrapper<Self>+'static>(t:Self) -> Self where Self: 'static {
+        Self::reduce_modulo(t, &A::VAL())
+    }
+    //An x with tx = 1 mod g, or None if there is none, or it cannot be found.
+    fn inverse_modulo(_t:&Self, _g:&Self) -> Option<Self> {
+        None
+    }
+    //The coefficients of the product of the polynomials with coefficients a and b, mod g, when
+    //there is a faster way to find them than multiplying in the quotient ring; None otherwise.
+    fn polynomial_product_modulo(_a:&[Self], _b:&[Self], _g:&Self) -> Option<Vec<Self>> {
+        None
+    }
+}
+//For rings with division with remainder, which also must be exact when g divides t.
+pub(crate) fn euclidean_divides<R:EuclideanRing<O>,O:RingOperations<R>>(g:&R, t:&R) -> bool {
+    t==&t.clone().quotient(g).times(g)
+}
+//Bézout gives tx + gy = d, and t is a unit mod g exactly when d is a unit.
+fn euclidean_inverse_modulo<R:EuclideanRing<O>,O:RingOperations<R>>(t:&R, g:&R) -> Option<R> {
+    let (x,y) = R::bézout(t.clone(), g.clone());
+    let d = t.clone().times(&x).plus(&g.clone().times(&y));
+    d.try_inverse().map(|u| x.times(&u))
+}
+impl<F:Field<O>,O:RingOperations<F>> Divisibility<O> for F where O::TIMES: O2<NonZero<F, O>>,NonZero<F, O>: Group<O::TIMES> {
+    fn divides(g:&Self, t:&Self) -> bool {
+        g!=&F::zero() || t==&F::zero()
+    }
+}
+impl Divisibility<StandardOps> for i64 {
+    fn divides(g:&Self, t:&Self) -> bool {
+        euclidean_divides(g, t)
+    }
+    fn reduce_modulo(t:Self, g:&Self) -> Self {
+        t.remainder(g)
+    }
+    fn inverse_modulo(t:&Self, g:&Self) -> Option<Self> {
+        euclidean_inverse_modulo(t, g)
+    }
+    fn polynomial_product_modulo(a:&[Self], b:&[Self], g:&Self) -> Option<Vec<Self>> {
+        ntt_product(a, b, *g)
+    }
+}
+impl Divisibility<StandardOps> for BigInt {
+    fn divides(g:&Self, t:&Self) -> bool {
+        euclidean_divides(g, t)
+    }
+    fn reduce_modulo(t:Self, g:&Self) -> Self {
+        t.remainder(g)
+    }
+    fn inverse_modulo(t:&Self, g:&Self) -> Option<Self> {
+        euclidean_inverse_modulo(t, g)
+    }
+}
+
 pub struct Multiples<R:Ring<O>,O:RingOperations<R>,A:Wrapper<R>> {
     pub data: R,
     pub o:PhantomData<O>,
@@ -26,9 +101,9 @@ impl<R:Ring<O>,O:RingOperations<R>,A:Wrapper<R>> Clone for Multiples<R,O,A> {
 impl<R:Ring<O>,O:RingOperations<R>,A:Wrapper<R>> Set for Multiples<R,O,A> {
     
 }
-impl<R:EuclideanRing<O>,O:RingOperations<R>,A:Wrapper<R>> Subset<R> for Multiples<R,O,A> {
+impl<R:Divisibility<O>,O:RingOperations<R>,A:Wrapper<R>> Subset<R> for Multiples<R,O,A> {
     fn contains(t: &R) -> bool {
-        t==&t.clone().quotient(&A::VAL()).times(&A::VAL())
+        R::divides(&A::VAL(), t)
     }
     fn inclusion(self) -> R {
         self.data
@@ -54,16 +129,22 @@ impl<R:Ring<O>,O:RingOperations<R>,A:Wrapper<R>> Group<O::PLUS> for Multiples<R,
         Multiples { data: self.data.inverse(), o: PhantomData, a: PhantomData }
     }
 }
-impl<R:EuclideanRing<O>,O:RingOperations<R>,A:Wrapper<R>> Subgroup<R,O::PLUS> for Multiples<R,O,A> {
+impl<R:Divisibility<O>,O:RingOperations<R>,A:Wrapper<R>> Subgroup<R,O::PLUS> for Multiples<R,O,A> {
     
 }
-impl<R:EuclideanRing<O>,O:RingOperations<R>,A:Wrapper<R>> AbsorbingSubset<R,O::TIMES> for Multiples<R,O,A> {
+impl<R:Divisibility<O>,O:RingOperations<R>,A:Wrapper<R>> AbsorbingSubset<R,O::TIMES> for Multiples<R,O,A> {
     fn times(self, m:&R) -> Self {
         Self { data: self.data.times(m), o: PhantomData, a: PhantomData }
     }
 }
-impl<R:EuclideanRing<O>,O:RingOperations<R>,A:Wrapper<R>> Ideal<R,O> for Multiples<R,O,A> {
+impl<R:Divisibility<O>+'static,O:RingOperations<R>,A:Wrapper<R>+'static> Ideal<R,O> for Multiples<R,O,A> {
     fn reduce(r: R) -> R {
-        r.remainder(&A::VAL())
+        R::reduce_modulo_fixed::<A>(r)
+    }
+    fn inverse_modulo(r: &R) -> Option<R> {
+        R::inverse_modulo(r, &A::VAL())
+    }
+    fn polynomial_product(a: &[R], b: &[R]) -> Option<Vec<R>> {
+        R::polynomial_product_modulo(a, b, &A::VAL())
     }
 }
\ No newline at end of file
@@ -0,0 +1,373 @@
+use std::{cmp::Ordering, collections::BTreeMap, marker::PhantomData, ops::{Add, Mul}};
+use crate::{structure::{ring::{RingOperations, Ring}, group::Group, monoid::Monoid}, set::Set, operation::O2};
+
+/// A total order on monomials that is compatible with multiplication: `a < b` implies
+/// `ac < bc`, and `1` is the least monomial. Exponent vectors are compared with `x_0` as the
+/// greatest variable.
+pub trait MonomialOrder {
+    fn cmp(a: &[usize], b: &[usize]) -> Ordering;
+}
+/// Lexicographic order: compare the exponent of `x_0`, then of `x_1`, and so on.
+pub struct Lex;
+/// Graded lexicographic order: compare total degrees, then break ties by `Lex`.
+pub struct GrLex;
+/// Graded reverse lexicographic order: compare total degrees, then the monomial with the smaller
+/// exponent in the last variable where they differ is the greater.
+pub struct GrevLex;
+impl MonomialOrder for Lex {
+    fn cmp(a: &[usize], b: &[usize]) -> Ordering {
+        a.cmp(b)
+    }
+}
+impl MonomialOrder for GrLex {
+    fn cmp(a: &[usize], b: &[usize]) -> Ordering {
+        let degree = |e: &[usize]| e.iter().sum::<usize>();
+        degree(a).cmp(&degree(b)).then_with(|| a.cmp(b))
+    }
+}
+impl MonomialOrder for GrevLex {
+    fn cmp(a: &[usize], b: &[usize]) -> Ordering {
+        let degree = |e: &[usize]| e.iter().sum::<usize>();
+        degree(a).cmp(&degree(b)).then_with(|| {
+            let last = a.iter().zip(b).rposition(|(i, j)| i != j);
+            last.map_or(Ordering::Equal, |k| b[k].cmp(&a[k]))
+        })
+    }
+}
+
+/// The monomial `x_0^e_0 ... x_(N-1)^e_(N-1)`, ordered by `M`.
+pub struct Monomial<const N: usize, M: MonomialOrder> {
+    pub exponents: [usize; N],
+    m: PhantomData<M>,
+}
+impl<const N: usize, M: MonomialOrder> Monomial<N, M> {
+    pub fn new(exponents: [usize; N]) -> Self {
+        Monomial { exponents, m: PhantomData }
+    }
+    pub fn one() -> Self {
+        Self::new([0; N])
+    }
+    /// The monomial `x_i`.
+    pub fn variable(i: usize) -> Self {
+        let mut exponents = [0; N];
+        exponents[i] = 1;
+        Self::new(exponents)
+    }
+    /// The total degree, the sum of the exponents.
+    pub fn degree(&self) -> usize {
+        self.exponents.iter().sum()
+    }
+    pub fn times(&self, other: &Self) -> Self {
+        Self::new(std::array::from_fn(|i| self.exponents[i] + other.exponents[i]))
+    }
+    pub fn divides(&self, other: &Self) -> bool {
+        self.exponents.iter().zip(&other.exponents).all(|(a, b)| a <= b)
+    }
+    /// `self / divisor`, or `None` if `divisor` does not divide `self`.
+    pub fn quotient(&self, divisor: &Self) -> Option<Self> {
+        if divisor.divides(self) {
+            Some(Self::new(std::array::from_fn(|i| self.exponents[i] - divisor.exponents[i])))
+        } else {
+            None
+        }
+    }
+    pub fn lcm(&self, other: &Self) -> Self {
+        Self::new(std::array::from_fn(|i| self.exponents[i].max(other.exponents[i])))
+    }
+    /// Whether no variable occurs in both monomials, i.e. their lcm is their product.
+    pub fn is_coprime(&self, other: &Self) -> bool {
+        self.exponents.iter().zip(&other.exponents).all(|(a, b)| *a == 0 || *b == 0)
+    }
+}
+impl<const N: usize, M: MonomialOrder> Clone for Monomial<N, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<const N: usize, M: MonomialOrder> Copy for Monomial<N, M> {}
+impl<const N: usize, M: MonomialOrder> PartialEq for Monomial<N, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.exponents == other.exponents
+    }
+}
+impl<const N: usize, M: MonomialOrder> Eq for Monomial<N, M> {}
+impl<const N: usize, M: MonomialOrder> PartialOrd for Monomial<N, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<const N: usize, M: MonomialOrder> Ord for Monomial<N, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        M::cmp(&self.exponents, &other.exponents)
+    }
+}
+
+/// A polynomial in the `N` variables `x_0, ..., x_(N-1)` over `R`, stored as a map from monomials
+/// to their nonzero coefficients. Iterating over `terms` visits monomials in increasing order
+/// with respect to `M`, so the leading term comes last.
+pub struct MultiPolynomial<R, O: RingOperations<R>, const N: usize, M: MonomialOrder = GrevLex>
+where
+    R: Ring<O>,
+{
+    pub terms: BTreeMap<Monomial<N, M>, R>,
+    pub o: PhantomData<O>,
+}
+impl<R, O, const N: usize, M> MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    pub fn zero() -> Self {
+        MultiPolynomial { terms: BTreeMap::new(), o: PhantomData }
+    }
+    pub fn one() -> Self {
+        Self::constant(R::one())
+    }
+    pub fn constant(c: R) -> Self {
+        Self::term(c, Monomial::one())
+    }
+    /// The polynomial `x_i`.
+    pub fn variable(i: usize) -> Self {
+        Self::term(R::one(), Monomial::variable(i))
+    }
+    /// The polynomial `c * m`.
+    pub fn term(c: R, m: Monomial<N, M>) -> Self {
+        Self::from_terms([(m, c)])
+    }
+    /// Sums the given terms. Monomials may repeat, and zero coefficients are dropped.
+    pub fn from_terms(terms: impl IntoIterator<Item = (Monomial<N, M>, R)>) -> Self {
+        let mut res = Self::zero();
+        for (m, c) in terms {
+            res.add_term(m, c);
+        }
+        res
+    }
+    fn add_term(&mut self, m: Monomial<N, M>, c: R) {
+        let sum = match self.terms.remove(&m) {
+            Some(d) => d.plus(&c),
+            None => c,
+        };
+        if sum != R::zero() {
+            self.terms.insert(m, sum);
+        }
+    }
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+    /// The coefficient of the monomial `m`.
+    pub fn coefficient(&self, m: &Monomial<N, M>) -> R {
+        self.terms.get(m).cloned().unwrap_or_else(R::zero)
+    }
+    /// The greatest monomial with a nonzero coefficient, or `None` for the zero polynomial.
+    pub fn leading_monomial(&self) -> Option<Monomial<N, M>> {
+        self.terms.keys().next_back().copied()
+    }
+    /// The coefficient of the leading monomial, or zero for the zero polynomial.
+    pub fn leading_coefficient(&self) -> R {
+        self.leading_term().map_or_else(R::zero, |(_, c)| c)
+    }
+    pub fn leading_term(&self) -> Option<(Monomial<N, M>, R)> {
+        self.terms.iter().next_back().map(|(m, c)| (*m, c.clone()))
+    }
+    /// The greatest total degree of a term, or `None` for the zero polynomial.
+    pub fn total_degree(&self) -> Option<usize> {
+        self.terms.keys().map(Monomial::degree).max()
+    }
+    /// The same polynomial with its terms ordered by `M2` instead.
+    pub fn with_order<M2: MonomialOrder>(&self) -> MultiPolynomial<R, O, N, M2> {
+        MultiPolynomial {
+            terms: self.terms.iter().map(|(m, c)| (Monomial::new(m.exponents), c.clone())).collect(),
+            o: PhantomData,
+        }
+    }
+    /// Evaluates at `x_i = point[i]`.
+    pub fn eval(&self, point: &[R; N]) -> R {
+        let mut res = R::zero();
+        for (m, c) in &self.terms {
+            let mut t = c.clone();
+            for (x, &e) in point.iter().zip(&m.exponents) {
+                if e > 0 {
+                    t = t.times(&Ring::pow(x.clone(), e as u64));
+                }
+            }
+            res = res.plus(&t);
+        }
+        res
+    }
+    /// `c * m * self`.
+    pub fn mul_term(&self, c: &R, m: &Monomial<N, M>) -> Self {
+        Self::from_terms(self.terms.iter().map(|(n, d)| (m.times(n), c.clone().times(d))))
+    }
+    fn add(mut self, other: &Self) -> Self {
+        for (m, c) in &other.terms {
+            self.add_term(*m, c.clone());
+        }
+        self
+    }
+    fn mul(self, other: &Self) -> Self {
+        let mut res = Self::zero();
+        for (m, c) in &self.terms {
+            for (n, d) in &other.terms {
+                res.add_term(m.times(n), c.clone().times(d));
+            }
+        }
+        res
+    }
+    fn negated(self) -> Self {
+        MultiPolynomial {
+            terms: self.terms.into_iter().map(|(m, c)| (m, c.negated())).collect(),
+            o: PhantomData,
+        }
+    }
+}
+impl<R, O, const N: usize, M> Add<MultiPolynomial<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+impl<R, O, const N: usize, M> Mul<MultiPolynomial<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+pub struct MPlus<R, O, const N: usize, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+    m: PhantomData<M>,
+}
+pub struct MTimes<R, O, const N: usize, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+    m: PhantomData<M>,
+}
+impl<R, O, const N: usize, M> O2<MultiPolynomial<R, O, N, M>> for MPlus<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    const F: fn(MultiPolynomial<R, O, N, M>, &MultiPolynomial<R, O, N, M>) -> MultiPolynomial<R, O, N, M> =
+        <MultiPolynomial<R, O, N, M>>::add;
+}
+impl<R, O, const N: usize, M> O2<MultiPolynomial<R, O, N, M>> for MTimes<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    const F: fn(MultiPolynomial<R, O, N, M>, &MultiPolynomial<R, O, N, M>) -> MultiPolynomial<R, O, N, M> =
+        <MultiPolynomial<R, O, N, M>>::mul;
+}
+impl<R, O, const N: usize, M> PartialEq for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+impl<R, O, const N: usize, M> Eq for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+}
+impl<R, O, const N: usize, M> Clone for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    fn clone(&self) -> Self {
+        MultiPolynomial { terms: self.terms.clone(), o: PhantomData }
+    }
+}
+impl<R, O, const N: usize, M> Set for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+}
+impl<R, O, const N: usize, M> Monoid<MTimes<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+impl<R, O, const N: usize, M> Monoid<MPlus<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+impl<R, O, const N: usize, M> Group<MPlus<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    fn inverse(self) -> Self {
+        self.negated()
+    }
+}
+pub struct MultiOps<R, O, const N: usize, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+    m: PhantomData<M>,
+}
+impl<R, O, const N: usize, M> RingOperations<MultiPolynomial<R, O, N, M>> for MultiOps<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+    type PLUS = MPlus<R, O, N, M>;
+    type TIMES = MTimes<R, O, N, M>;
+}
+impl<R, O, const N: usize, M> Ring<MultiOps<R, O, N, M>> for MultiPolynomial<R, O, N, M>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+    M: MonomialOrder,
+{
+}
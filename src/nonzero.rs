@@ -37,7 +37,7 @@ where
     O: RingOperations<R>,
 {
     fn contains(r: &R) -> bool {
-        r == &R::zero()
+        r != &R::zero()
     }
     fn inclusion(self) -> R {
         self.r
@@ -53,3 +53,4 @@ where
         }
     }
 }
+
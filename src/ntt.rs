@@ -0,0 +1,121 @@
+use crate::{
+    arithmetic::{is_prime, mul_mod, pow_mod},
+    quotient::IntegersMod,
+};
+
+/// Below this many coefficients in the shorter factor, `ntt_product` leaves the product to
+/// Karatsuba or Toom-3.
+const NTT_THRESHOLD: usize = 64;
+
+/// The largest `k` such that `2^k` divides `P-1`. Transforms of every length up to `2^k` exist
+/// mod `P`, and of no other power-of-two length.
+pub fn two_adicity<const P: i64>() -> u32 {
+    (P - 1).trailing_zeros()
+}
+/// A primitive `n`-th root of unity mod the prime `P`, where `n` is a power of two. Returns `None`
+/// if `n` does not divide `P-1`, in which case there is none.
+pub fn root_of_unity<const P: i64>(n: usize) -> Option<IntegersMod<P>> {
+    root_of_unity_residue(P as u64, n).map(|w| IntegersMod::from(w as i64))
+}
+/// A primitive `n`-th root of unity mod `p`, or `None` if there is none or `p` is not prime: the
+/// search relies on the multiplicative group being cyclic of order `p-1`.
+fn root_of_unity_residue(p: u64, n: usize) -> Option<u64> {
+    if !is_prime(p) {
+        return None;
+    }
+    let k = (p - 1).trailing_zeros();
+    if !n.is_power_of_two() || n.trailing_zeros() > k {
+        return None;
+    }
+    if n == 1 {
+        return Some(1);
+    }
+    let odd = (p - 1) >> k;
+    // a^odd has order exactly 2^k precisely when a is a quadratic non-residue.
+    let w = (2..p)
+        .map(|a| pow_mod(a, odd, p))
+        .find(|&w| pow_mod(w, 1 << (k - 1), p) == p - 1)?;
+    Some(pow_mod(w, 1 << (k - n.trailing_zeros()), p))
+}
+/// In-place iterative Cooley–Tukey transform of residues in `0..p`, with `w` a primitive
+/// `a.len()`-th root of unity.
+fn transform(a: &mut [u64], w: u64, p: u64) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let step = pow_mod(w, (n / len) as u64, p);
+        for start in (0..n).step_by(len) {
+            let mut twiddle = 1;
+            for i in start..start + len / 2 {
+                let u = a[i];
+                let v = mul_mod(a[i + len / 2], twiddle, p);
+                a[i] = (u + v) % p;
+                a[i + len / 2] = (u + p - v) % p;
+                twiddle = mul_mod(twiddle, step, p);
+            }
+        }
+        len *= 2;
+    }
+}
+/// The number-theoretic transform of `a`, i.e. the values of the polynomial with coefficients `a`
+/// at the powers of a primitive `a.len()`-th root of unity. Panics if `a.len()` is not a power of
+/// two dividing `P-1`.
+pub fn ntt<const P: i64>(a: &[IntegersMod<P>]) -> Vec<IntegersMod<P>> {
+    let p = P as u64;
+    let w = root_of_unity_residue(p, a.len()).unwrap();
+    let mut v: Vec<u64> = a.iter().map(|c| c.residue() as u64).collect();
+    transform(&mut v, w, p);
+    v.into_iter().map(|c| IntegersMod::from(c as i64)).collect()
+}
+/// The inverse of [`ntt`].
+pub fn inverse_ntt<const P: i64>(a: &[IntegersMod<P>]) -> Vec<IntegersMod<P>> {
+    let p = P as u64;
+    let w = root_of_unity_residue(p, a.len()).unwrap();
+    let mut v: Vec<u64> = a.iter().map(|c| c.residue() as u64).collect();
+    transform(&mut v, pow_mod(w, p - 2, p), p);
+    let n_inverse = pow_mod(a.len() as u64, p - 2, p);
+    v.into_iter()
+        .map(|c| IntegersMod::from(mul_mod(c, n_inverse, p) as i64))
+        .collect()
+}
+/// The coefficients of the product of the polynomials with coefficients `a` and `b` mod `p`, in
+/// `O(n log n)` by a number-theoretic transform. Returns `None` for small inputs, when `p` is not
+/// prime, and when `p-1` is not divisible by a large enough power of two, so that the caller can
+/// multiply some other way. Integer polynomials mod `p` are multiplied through this.
+pub(crate) fn ntt_product(a: &[i64], b: &[i64], p: i64) -> Option<Vec<i64>> {
+    let (m, n) = (a.len(), b.len());
+    if m.min(n) < NTT_THRESHOLD || p < 2 {
+        return None;
+    }
+    let size = (m + n).next_power_of_two();
+    let p = p as u64;
+    let w = root_of_unity_residue(p, size)?;
+    let pad = |c: &[i64]| {
+        let mut v: Vec<u64> = c.iter().map(|c| c.rem_euclid(p as i64) as u64).collect();
+        v.resize(size, 0);
+        transform(&mut v, w, p);
+        v
+    };
+    let (a, b) = (pad(a), pad(b));
+    let mut c: Vec<u64> = a.iter().zip(&b).map(|(x, y)| mul_mod(*x, *y, p)).collect();
+    transform(&mut c, pow_mod(w, p - 2, p), p);
+    let n_inverse = pow_mod(size as u64, p - 2, p);
+    c.truncate(m + n - 1);
+    Some(
+        c.into_iter()
+            .map(|x| mul_mod(x, n_inverse, p) as i64)
+            .collect(),
+    )
+}
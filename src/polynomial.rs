@@ -1,6 +1,13 @@
-use std::{marker::PhantomData, ops::{Add, Mul}, cmp::Ordering};
-use crate::{structure::{ring::{RingOperations, Ring, Ideal}, group::{Group, Subgroup}, monoid::{Monoid, AbsorbingSubset}, field::Field, euclidean_ring::EuclideanRing}, set::{Set, Subset}, operation::O2, nonzero::NonZero, unit::TryInverse, wrapper::Wrapper, modular::Multiples, quotient::QuotientRing, impls::StandardOps};
+use std::{marker::PhantomData, ops::{Add, Mul}, cmp::Ordering, rc::Rc};
+use crate::{structure::{ring::{RingOperations, Ring}, group::Group, monoid::Monoid, field::Field, euclidean_ring::EuclideanRing}, set::Set, operation::O2, nonzero::NonZero, unit::TryInverse, modular::Divisibility, division::PreparedDivisor, wrapper::Wrapper, cache::cached};
 use take_mut::take;
+/// Below this many coefficients `Polynomial::mul` uses schoolbook multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+/// From this many coefficients `Polynomial::mul` uses Toom-3, where 2 and 3 are units.
+const TOOM3_THRESHOLD: usize = 150;
+/// Below this many coefficients in the divisor or the quotient `Polynomial::divide` uses long
+/// division.
+const NEWTON_DIVISION_THRESHOLD: usize = 64;
 pub struct Polynomial<R, O: RingOperations<R>>
 where
     R: Ring<O>,
@@ -15,7 +22,7 @@ pub enum Degree {
 }
 impl Degree {
     /// Panics if the degree is `Degree::NegInfty`.
-    fn unwrap(self) -> usize {
+    pub fn unwrap(self) -> usize {
         match self {
             Self::Integer(n) => n,
             Self::NegInfty => panic!(),
@@ -73,38 +80,46 @@ where
         }
         v
     }
-    fn zero() -> Self {
+    /// The polynomial with the given coefficients, constant term first. Trailing zeros are
+    /// dropped.
+    pub fn from_coefficients(coefficients: Vec<R>) -> Self {
+        Polynomial {
+            coefficients: Self::trim_zeros(coefficients),
+            o: PhantomData,
+        }
+    }
+    pub fn zero() -> Self {
         Polynomial {
             coefficients: vec![],
             o: PhantomData,
         }
     }
-    fn one() -> Self {
+    pub fn one() -> Self {
         Polynomial {
             coefficients: vec![R::one()],
             o: PhantomData,
         }
     }
-    fn x() -> Self {
+    pub fn x() -> Self {
         Polynomial {
-            coefficients: vec![R::one(), R::zero()],
+            coefficients: vec![R::zero(), R::one()],
             o: PhantomData,
         }
     }
-    fn constant(c: R) -> Self {
+    pub fn constant(c: R) -> Self {
         Polynomial {
             coefficients: Self::trim_zeros(vec![c]),
             o: PhantomData,
         }
     }
-    fn degree(&self) -> Degree {
+    pub fn degree(&self) -> Degree {
         if self.coefficients.is_empty() {
             Degree::NegInfty
         } else {
             Degree::Integer(self.coefficients.len() - 1)
         }
     }
-    fn coefficient(&self, n: usize) -> R {
+    pub fn coefficient(&self, n: usize) -> R {
         if self.degree() >= n {
             self.coefficients[n].clone()
         } else {
@@ -124,26 +139,159 @@ where
         self
     }
     fn mul(self, other: &Self) -> Self {
-        let l = self.degree() + other.degree();
-        let mut res = vec![];
-        let mut i = 0;
-        while l >= i {
-            let mut s = R::zero();
-            for j in 0..=i {
-                s = Ring::plus(
-                    s,
-                    &self.coefficient(j).times( &other.coefficient(i - j)),
-                );
-            }
-            res.push(s);
-            i += 1;
-        }
-        res = Self::trim_zeros(res);
         Polynomial {
-            coefficients: res,
+            coefficients: Self::trim_zeros(R::polynomial_product(&self.coefficients, &other.coefficients)),
             o: PhantomData,
         }
     }
+    /// res[offset..] += v, growing res if needed.
+    fn add_at(res: &mut Vec<R>, offset: usize, v: &[R]) {
+        if res.len() < offset + v.len() {
+            res.resize(offset + v.len(), R::zero());
+        }
+        for (i, c) in v.iter().enumerate() {
+            take(&mut res[offset + i], |r| r.plus(c));
+        }
+    }
+    /// res[offset..] -= v, growing res if needed.
+    fn sub_at(res: &mut Vec<R>, offset: usize, v: &[R]) {
+        if res.len() < offset + v.len() {
+            res.resize(offset + v.len(), R::zero());
+        }
+        for (i, c) in v.iter().enumerate() {
+            take(&mut res[offset + i], |r| r.minus(c));
+        }
+    }
+    fn schoolbook(a: &[R], b: &[R]) -> Vec<R> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut res = vec![R::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                take(&mut res[i + j], |r| r.plus(&x.clone().times(y)));
+            }
+        }
+        res
+    }
+    /// Multiplies a short `a` by a long `b` by cutting `b` into pieces of the length of `a`.
+    fn unbalanced(a: &[R], b: &[R], mul: impl Fn(&[R], &[R]) -> Vec<R>) -> Vec<R> {
+        let mut res = vec![];
+        for (i, chunk) in b.chunks(a.len()).enumerate() {
+            Self::add_at(&mut res, i * a.len(), &mul(a, chunk));
+        }
+        res
+    }
+    /// Karatsuba multiplication of coefficient slices. Uses three half-size products instead of
+    /// four, and only ring additions and multiplications, so it works over any ring.
+    fn karatsuba(a: &[R], b: &[R]) -> Vec<R> {
+        let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        if a.len() < KARATSUBA_THRESHOLD {
+            return Self::schoolbook(a, b);
+        }
+        if 2 * a.len() <= b.len() {
+            return Self::unbalanced(a, b, Self::karatsuba);
+        }
+        let m = b.len() / 2;
+        let (a0, a1) = a.split_at(m);
+        let (b0, b1) = b.split_at(m);
+        let z0 = Self::karatsuba(a0, b0);
+        let z2 = Self::karatsuba(a1, b1);
+        let mut sa = a0.to_vec();
+        Self::add_at(&mut sa, 0, a1);
+        let mut sb = b0.to_vec();
+        Self::add_at(&mut sb, 0, b1);
+        let mut z1 = Self::karatsuba(&sa, &sb);
+        Self::sub_at(&mut z1, 0, &z0);
+        Self::sub_at(&mut z1, 0, &z2);
+        let mut res = z0;
+        Self::add_at(&mut res, m, &z1);
+        Self::add_at(&mut res, 2 * m, &z2);
+        res.truncate(a.len() + b.len() - 1);
+        res
+    }
+    /// Multiplies every coefficient by `c`.
+    fn scaled(mut v: Vec<R>, c: &R) -> Vec<R> {
+        for x in v.iter_mut() {
+            take(x, |x| x.times(c));
+        }
+        v
+    }
+    /// Toom-3 multiplication: five third-size products instead of nine. The interpolation
+    /// divides by 2 and 3, which it does by multiplying by their `inverses`.
+    fn toom3(a: &[R], b: &[R], inverses: &(R, R)) -> Vec<R> {
+        let (a, b) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+        if a.len() < TOOM3_THRESHOLD {
+            return Self::karatsuba(a, b);
+        }
+        if 2 * a.len() <= b.len() {
+            return Self::unbalanced(a, b, |a, b| Self::toom3(a, b, inverses));
+        }
+        let k = b.len().div_ceil(3);
+        let split = |v: &[R]| {
+            let (v0, rest) = v.split_at(k);
+            let (v1, v2) = rest.split_at(k.min(rest.len()));
+            (v0.to_vec(), v1.to_vec(), v2.to_vec())
+        };
+        // Evaluate at 0, 1, -1, -2 and infinity.
+        let evaluate = |(v0, v1, v2): (Vec<R>, Vec<R>, Vec<R>)| {
+            let mut p1 = v0.clone();
+            Self::add_at(&mut p1, 0, &v2);
+            let mut pm1 = p1.clone();
+            Self::add_at(&mut p1, 0, &v1);
+            Self::sub_at(&mut pm1, 0, &v1);
+            // pm2 = ((2v2 - v1)*2 + v0)
+            let mut pm2 = v2.clone();
+            Self::add_at(&mut pm2, 0, &v2);
+            Self::sub_at(&mut pm2, 0, &v1);
+            let twice = pm2.clone();
+            Self::add_at(&mut pm2, 0, &twice);
+            Self::add_at(&mut pm2, 0, &v0);
+            (v0, p1, pm1, pm2, v2)
+        };
+        let (a0, a1, am1, am2, ainf) = evaluate(split(a));
+        let (b0, b1, bm1, bm2, binf) = evaluate(split(b));
+        let r0 = Self::toom3(&a0, &b0, inverses);
+        let r1 = Self::toom3(&a1, &b1, inverses);
+        let rm1 = Self::toom3(&am1, &bm1, inverses);
+        let rm2 = Self::toom3(&am2, &bm2, inverses);
+        let rinf = Self::toom3(&ainf, &binf, inverses);
+        // Bodrato's interpolation sequence.
+        let mut r3 = rm2;
+        Self::sub_at(&mut r3, 0, &r1);
+        let r3 = Self::scaled(r3, &inverses.1);
+        let mut s1 = r1;
+        Self::sub_at(&mut s1, 0, &rm1);
+        let s1 = Self::scaled(s1, &inverses.0);
+        let mut r2 = rm1;
+        Self::sub_at(&mut r2, 0, &r0);
+        let mut t3 = r2.clone();
+        Self::sub_at(&mut t3, 0, &r3);
+        let mut r3 = Self::scaled(t3, &inverses.0);
+        Self::add_at(&mut r3, 0, &rinf);
+        Self::add_at(&mut r3, 0, &rinf);
+        Self::add_at(&mut r2, 0, &s1);
+        Self::sub_at(&mut r2, 0, &rinf);
+        let mut r1 = s1;
+        Self::sub_at(&mut r1, 0, &r3);
+        let mut res = r0;
+        Self::add_at(&mut res, k, &r1);
+        Self::add_at(&mut res, 2 * k, &r2);
+        Self::add_at(&mut res, 3 * k, &r3);
+        Self::add_at(&mut res, 4 * k, &rinf);
+        res.truncate(a.len() + b.len() - 1);
+        res
+    }
+    /// The product of coefficient slices by Toom-3 from `TOOM3_THRESHOLD` coefficients when the
+    /// inverses of 2 and 3 are given, and by Karatsuba otherwise, which itself uses schoolbook
+    /// multiplication below `KARATSUBA_THRESHOLD`. Rings pass the inverses from
+    /// `Ring::polynomial_product` when they know them.
+    pub(crate) fn product(a: &[R], b: &[R], inverses: Option<&(R, R)>) -> Vec<R> {
+        match inverses {
+            Some(inverses) => Self::toom3(a, b, inverses),
+            None => Self::karatsuba(a, b),
+        }
+    }
 
     fn negated(self) -> Self {
         let mut res = vec![];
@@ -155,29 +303,108 @@ where
             o: PhantomData,
         }
     }
-    //fn derivative(self)->Self {
-    //self
-    //}
+    /// The formal derivative. The coefficient of `x^i` is multiplied by `i`, computed as
+    /// `R::from_integer(i)`, so this makes sense over any ring.
+    pub fn derivative(&self) -> Self {
+        self.nth_derivative(1)
+    }
+    /// The `k`-th formal derivative. The coefficient of `x^i` is multiplied by the falling
+    /// factorial `i(i-1)...(i-k+1)` rather than by `i!/(i-k)!`, so no division is needed.
+    pub fn nth_derivative(&self, k: usize) -> Self {
+        let mut res = vec![];
+        for i in k..self.coefficients.len() {
+            let mut c = self.coefficients[i].clone();
+            for j in (i - k + 1)..=i {
+                c = c.times(&R::from_integer(j as u64));
+            }
+            res.push(c);
+        }
+        Polynomial {
+            coefficients: Self::trim_zeros(res),
+            o: PhantomData,
+        }
+    }
+    /// The Taylor shift `f(x+a)`, computed by Horner's rule in `O(n^2)` ring operations.
+    pub fn taylor_shift(&self, a: &R) -> Self {
+        let mut res: Vec<R> = vec![];
+        for c in self.coefficients.iter().rev() {
+            // res <- res*(x+a) + c
+            res.push(R::zero());
+            for j in (1..res.len()).rev() {
+                let shifted = res[j - 1].clone();
+                take(&mut res[j], |r| shifted.plus(&r.times(a)));
+            }
+            take(&mut res[0], |r| r.times(a).plus(c));
+        }
+        Polynomial {
+            coefficients: Self::trim_zeros(res),
+            o: PhantomData,
+        }
+    }
+    /// The composition `f(g(x))`, computed by Horner's rule.
+    pub fn compose(&self, g: &Self) -> Self {
+        let mut res = Self::zero();
+        for c in self.coefficients.iter().rev() {
+            res = res.mul(g).add(&Self::constant(c.clone()));
+        }
+        res
+    }
+    /// Evaluates at `x` by Horner's rule, with one multiplication per coefficient.
     pub fn of(&self, x: R) -> R {
-        let mut n = 0;
         let mut res = R::zero();
-        let mut xPower = R::one();
-        while self.degree() >= n {
-            res = res.plus(&self.coefficient(n).times( &xPower));
-            xPower = xPower.times( &x);
-            n += 1;
+        for c in self.coefficients.iter().rev() {
+            res = res.times(&x).plus(c);
         }
         res
     }
+    /// Pseudo-division, which needs no inverses. Returns `(q, r)` such that
+    /// `lc(divisor)^δ * self = q * divisor + r` with `deg r < deg divisor`, where
+    /// `δ = max(deg self - deg divisor + 1, 0)`. Assumes the ring is commutative.
+    /// Panics if the divisor is zero.
+    pub fn pseudo_divide(&self, divisor: &Self) -> (Self, Self) {
+        let n = divisor.degree().unwrap();
+        let b = divisor.lead_coeff();
+        let mut r = self.coefficients.clone();
+        if r.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+        let mut q = vec![R::zero(); r.len() - n];
+        for k in (n..r.len()).rev() {
+            // q <- b*q + c*x^(k-n), r <- b*r - c*x^(k-n)*divisor, which clears r[k].
+            let c = r.pop().unwrap();
+            for e in q.iter_mut() {
+                take(e, |e| e.times(&b));
+            }
+            q[k - n] = c.clone();
+            for e in r.iter_mut() {
+                take(e, |e| e.times(&b));
+            }
+            for (i, d) in divisor.coefficients[..n].iter().enumerate() {
+                take(&mut r[k - n + i], |e| e.minus(&c.clone().times(d)));
+            }
+        }
+        (
+            Polynomial { coefficients: Self::trim_zeros(q), o: PhantomData },
+            Polynomial { coefficients: Self::trim_zeros(r), o: PhantomData },
+        )
+    }
+    /// The pseudo-remainder; see [`Polynomial::pseudo_divide`].
+    pub fn prem(&self, divisor: &Self) -> Self {
+        self.pseudo_divide(divisor).1
+    }
+    /// The pseudo-quotient; see [`Polynomial::pseudo_divide`].
+    pub fn pquo(&self, divisor: &Self) -> Self {
+        self.pseudo_divide(divisor).0
+    }
     /// Gives the leading coefficient of self. The result is guaranteed to be nonzero. Panics if
     /// given the zero polynomial, which has no leading coefficient.
-    fn lead_coeff(&self) -> R {
+    pub fn lead_coeff(&self) -> R {
         match self.degree() {
             Degree::Integer(n) => self.coefficient(n),
             Degree::NegInfty => panic!(),
         }
     }
-    fn x_pow(n:usize) -> Self{
+    pub fn x_pow(n:usize) -> Self{
         let mut data = Vec::with_capacity(n+1);
         for _ in 0..n {
             data.push(R::zero());
@@ -191,19 +418,97 @@ where R:TryInverse<O>
 {
     /// The leading coefficient of the divisor must be a unit.
     /// Panics if this is not the case.
-    fn divide(mut dividend: Self, divisor: &Self) -> (Self, Self) {
+    ///
+    /// Long division costs `O(deg q * deg divisor)`, so when both are large this goes through
+    /// [`PreparedDivisor`] instead, which costs a few multiplications.
+    pub fn divide(dividend: Self, divisor: &Self) -> (Self, Self) {
         let n = divisor.degree().unwrap();
         let i = divisor.lead_coeff().try_inverse().unwrap();
-        let mut quotient = Self::zero();
-        while dividend.degree() >= divisor.degree() {
-            let m = dividend.degree().unwrap();
-            let term = 
-                Self::constant(dividend.lead_coeff().times(&i)).times(
-                &Self::x_pow(m - n));
-            quotient = quotient + term.clone();
-            dividend = dividend + (divisor.clone() * term).negated();
+        let m = match dividend.degree() {
+            Degree::Integer(m) if m >= n => m,
+            _ => return (Self::zero(), dividend),
+        };
+        if m - n + 1 >= NEWTON_DIVISION_THRESHOLD && n >= NEWTON_DIVISION_THRESHOLD {
+            return PreparedDivisor::new(divisor.clone(), m - n + 1).divide(dividend);
+        }
+        let mut r = dividend.coefficients;
+        let mut q = vec![R::zero(); m - n + 1];
+        for k in (n..=m).rev() {
+            let c = r.pop().unwrap().times(&i);
+            for (j, d) in divisor.coefficients[..n].iter().enumerate() {
+                take(&mut r[k - n + j], |e| e.minus(&c.clone().times(d)));
+            }
+            q[k - n] = c;
+        }
+        (Self::from_coefficients(q), Self::from_coefficients(r))
+    }
+}
+impl<R:EuclideanRing<O>, O:RingOperations<R>> Polynomial<R, O> {
+    /// Divides every coefficient by `c`. The division must be exact.
+    fn exact_scalar_quotient(&self, c: &R) -> Self {
+        Polynomial {
+            coefficients: Self::trim_zeros(self.coefficients.iter().map(|a| a.clone().quotient(c)).collect()),
+            o: PhantomData,
+        }
+    }
+    /// The gcd of the coefficients, times the unit that makes the leading coefficient of the
+    /// primitive part normalized. So over the integers the primitive part has positive leading
+    /// coefficient, and over a field the content is the leading coefficient. Zero has content zero.
+    pub fn content(&self) -> R {
+        let g = self.coefficients.iter().fold(R::zero(), |a, c| R::gcd(a, c.clone())).normalized();
+        match self.degree() {
+            Degree::NegInfty => g,
+            Degree::Integer(_) => {
+                let lc = self.lead_coeff();
+                let unit = lc.clone().quotient(&lc.normalized());
+                g.times(&unit)
+            }
+        }
+    }
+    /// `self` divided by its content. Zero is its own primitive part.
+    pub fn primitive_part(&self) -> Self {
+        if self.coefficients.is_empty() {
+            return self.clone();
+        }
+        self.exact_scalar_quotient(&self.content())
+    }
+    /// The gcd of two polynomials over a GCD domain, by the subresultant polynomial remainder
+    /// sequence. Every remainder is divided by a known factor, which keeps the coefficients from
+    /// growing exponentially without computing contents at each step. The result is primitive
+    /// part times gcd of the contents, with normalized leading coefficient.
+    pub fn subresultant_gcd(f: &Self, g: &Self) -> Self {
+        if f.coefficients.is_empty() {
+            return g.primitive_part().times(&Self::constant(g.content().normalized()));
+        }
+        if g.coefficients.is_empty() {
+            return Self::subresultant_gcd(g, f);
         }
-        (quotient, dividend)
+        let (f, g) = if f.degree() >= g.degree() { (f, g) } else { (g, f) };
+        let c = R::gcd(f.content(), g.content()).normalized();
+        let mut a = f.primitive_part();
+        let mut b = g.primitive_part();
+        let mut lc_power = R::one();
+        let mut h = R::one();
+        loop {
+            let delta = a.degree().unwrap() - b.degree().unwrap();
+            let r = a.prem(&b);
+            if r.coefficients.is_empty() {
+                break;
+            }
+            if r.degree() == 0 {
+                b = Self::one();
+                break;
+            }
+            a = b;
+            b = r.exact_scalar_quotient(&lc_power.times(&Ring::pow(h.clone(), delta as u64)));
+            lc_power = a.lead_coeff();
+            h = if delta == 0 {
+                h
+            } else {
+                Ring::pow(lc_power.clone(), delta as u64).quotient(&Ring::pow(h, delta as u64 - 1))
+            };
+        }
+        b.primitive_part().times(&Self::constant(c))
     }
 }
 impl<R, O> Add<Polynomial<R, O>> for Polynomial<R, O>
@@ -226,7 +531,7 @@ where
         self.mul(&rhs)
     }
 }
-struct PPLUS<R, O>
+pub struct PPLUS<R, O>
 where
     O: RingOperations<R>,
     R: Ring<O>,
@@ -234,7 +539,7 @@ where
     r: PhantomData<R>,
     o: PhantomData<O>,
 }
-struct PTIMES<R, O>
+pub struct PTIMES<R, O>
 where
     O: RingOperations<R>,
     R: Ring<O>,
@@ -317,7 +622,7 @@ where
         self.negated()
     }
 }
-struct PolyOps<R, O>
+pub struct PolyOps<R, O>
 where
     O: RingOperations<R>,
     R: Ring<O>,
@@ -358,35 +663,74 @@ where
     fn remainder(self,divisor:&Self) -> Self {
         Self::divide(self, divisor).1
     }
-}
-impl<P:Wrapper<Polynomial<i64,StandardOps>>> Subset<Polynomial<i64,StandardOps>> for Multiples<Polynomial<i64,StandardOps>,PolyOps<i64,StandardOps>,P> {
-    fn contains(t: &Polynomial<i64,StandardOps>) -> bool {
-        Polynomial::divide(t.clone(), &P::VAL()).1==Polynomial::zero()
+    fn normalized(self) -> Self {
+        self.primitive_part()
     }
-    fn inclusion(self) -> Polynomial<i64,StandardOps> {
-        self.data
+    //Both go through the half-GCD, which gives the same results as the default classical Euclid.
+    fn bézout(a:Self,b:Self)->(Self,Self){
+        let (_, x, y) = Self::fast_bézout(a, b);
+        (x, y)
     }
-    fn try_from(t: Polynomial<i64,StandardOps>) -> Self {
-        if Self::contains(&t) {
-            Self {data: t,o:PhantomData,a:PhantomData}
-        } else {
-            panic!()
-        }
+    fn gcd(a:Self,b:Self) -> Self {
+        Self::fast_gcd(a, b)
     }
 }
-impl<P:Wrapper<Polynomial<i64,StandardOps>>> Subgroup<Polynomial<i64,StandardOps>,PPLUS<i64,StandardOps>> for Multiples<Polynomial<i64,StandardOps>,PolyOps<i64,StandardOps>,P> {
-    
-}
-impl<P:Wrapper<Polynomial<i64,StandardOps>>> AbsorbingSubset<Polynomial<i64,StandardOps>,PTIMES<i64,StandardOps>> for Multiples<Polynomial<i64,StandardOps>,PolyOps<i64,StandardOps>,P> {
-    
-}
-
-impl<P:Wrapper<Polynomial<i64,StandardOps>>> Ideal<Polynomial<i64,StandardOps>,PolyOps<i64,StandardOps>> for Multiples<Polynomial<i64,StandardOps>,PolyOps<i64,StandardOps>,P> {
-    
+impl<F, O> Polynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// The modular composition `f(g(x)) mod h`. Every intermediate result is reduced mod `h`,
+    /// so nothing of degree `2deg(h)` or more is ever formed. Panics if `h` is zero.
+    pub fn compose_mod(&self, g: &Self, h: &Self) -> Self {
+        let g = g.clone().remainder(h);
+        let mut res = Self::zero();
+        for c in self.coefficients.iter().rev() {
+            res = res.mul(&g).add(&Self::constant(c.clone())).remainder(h);
+        }
+        res
+    }
 }
-type i64AdjX=Polynomial<i64,StandardOps>;
-struct XSquaredPlus1;
-impl Wrapper<i64AdjX> for XSquaredPlus1 {
-    const VAL: fn()->i64AdjX = || i64AdjX::x().times(&i64AdjX::x()).plus(&i64AdjX::one());
+impl<R:TryInverse<O>, O:RingOperations<R>> Divisibility<PolyOps<R,O>> for Polynomial<R, O> {
+    //When the leading coefficient of g is a unit, division by g stays in R[x]. Otherwise it is up
+    //to R, e.g. by Gauss's lemma for any `EuclideanRing`.
+    fn divides(g:&Self, t:&Self) -> bool {
+        if g.coefficients.is_empty() {
+            return t.coefficients.is_empty();
+        }
+        if g.lead_coeff().try_inverse().is_some() {
+            return Polynomial::divide(t.clone(), g).1 == Polynomial::zero();
+        }
+        R::polynomial_divides(g, t)
+    }
+    //The remainder is canonical when g is monic up to a unit. There is no such choice in general
+    //otherwise, and only multiples of g are reduced to zero.
+    fn reduce_modulo(t:Self, g:&Self) -> Self {
+        if !g.coefficients.is_empty() && g.lead_coeff().try_inverse().is_some() {
+            Polynomial::divide(t, g).1
+        } else if Self::divides(g, &t) {
+            Polynomial::zero()
+        } else {
+            t
+        }
+    }
+    //A QuotientRing mostly reduces products of two remainders, whose quotients have degree below
+    //deg g, so that is the precision of the cached inverse. Like `divide`, small moduli and short
+    //quotients use long division.
+    fn reduce_modulo_fixed<A:Wrapper<Self>+'static>(t:Self) -> Self where Self: 'static {
+        let prepared = cached::<A, _>(|| {
+            let g = A::VAL();
+            let monic = !g.coefficients.is_empty() && g.lead_coeff().try_inverse().is_some();
+            let n = g.coefficients.len().saturating_sub(1);
+            (monic && n >= NEWTON_DIVISION_THRESHOLD).then(|| Rc::new(PreparedDivisor::new(g, n)))
+        });
+        match prepared {
+            Some(p) if t.coefficients.len() >= p.divisor().coefficients.len() + NEWTON_DIVISION_THRESHOLD - 1 => {
+                p.remainder(t)
+            }
+            _ => Self::reduce_modulo(t, &A::VAL()),
+        }
+    }
 }
-type GaussianI64=QuotientRing<i64AdjX,PolyOps<i64,StandardOps>,Multiples<i64AdjX,PolyOps<i64,StandardOps>,XSquaredPlus1>>;
\ No newline at end of file
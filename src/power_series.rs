@@ -0,0 +1,320 @@
+use std::{marker::PhantomData, ops::{Add, Mul}};
+use crate::{
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::Polynomial,
+    set::Set,
+    structure::{field::Field, group::Group, monoid::Monoid, ring::{Ring, RingOperations}},
+    unit::TryInverse,
+};
+
+/// A formal power series over `R` known modulo `x^PREC`, that is, its first `PREC` coefficients.
+/// Every operation truncates its result, so products never carry terms that are not known.
+/// Trailing zero coefficients are removed.
+pub struct PowerSeries<R, O: RingOperations<R>, const PREC: usize>
+where
+    R: Ring<O>,
+{
+    pub coefficients: Vec<R>,
+    pub o: PhantomData<O>,
+}
+
+/// The first `n` coefficients of `a * b`.
+pub(crate) fn mul_truncated<R: Ring<O>, O: RingOperations<R>>(a: &[R], b: &[R], n: usize) -> Vec<R> {
+    let a = Polynomial::<R, O>::from_coefficients(a.iter().take(n).cloned().collect());
+    let b = Polynomial::<R, O>::from_coefficients(b.iter().take(n).cloned().collect());
+    let mut c = a.times(&b).coefficients;
+    c.truncate(n);
+    c
+}
+/// `c - a`, where `c` is a constant.
+fn constant_minus<R: Ring<O>, O: RingOperations<R>>(c: R, a: &[R]) -> Vec<R> {
+    let mut res: Vec<R> = a.iter().cloned().map(R::negated).collect();
+    if res.is_empty() {
+        res.push(R::zero());
+    }
+    res[0] = res[0].clone().plus(&c);
+    res
+}
+/// The first `n` coefficients of `1 / f` by Newton iteration, `g <- g (2 - f g)`, which doubles
+/// the number of correct coefficients at each step.
+pub(crate) fn inverse_truncated<R: Ring<O> + TryInverse<O>, O: RingOperations<R>>(f: &[R], n: usize) -> Option<Vec<R>> {
+    let mut g = vec![f.first()?.clone().try_inverse()?];
+    let mut k = 1;
+    while k < n {
+        k = (2 * k).min(n);
+        let e = constant_minus(R::from_integer(2), &mul_truncated(f, &g, k));
+        g = mul_truncated(&g, &e, k);
+    }
+    g.truncate(n);
+    Some(g)
+}
+
+impl<R, O, const PREC: usize> PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    /// The series with the given coefficients, constant term first, truncated to `PREC` terms.
+    pub fn from_coefficients(mut coefficients: Vec<R>) -> Self {
+        coefficients.truncate(PREC);
+        while coefficients.last() == Some(&R::zero()) {
+            coefficients.pop();
+        }
+        PowerSeries { coefficients, o: PhantomData }
+    }
+    pub fn zero() -> Self {
+        Self::from_coefficients(vec![])
+    }
+    pub fn one() -> Self {
+        Self::constant(R::one())
+    }
+    pub fn x() -> Self {
+        Self::from_coefficients(vec![R::zero(), R::one()])
+    }
+    pub fn constant(c: R) -> Self {
+        Self::from_coefficients(vec![c])
+    }
+    /// The coefficient of `x^n`. Panics if `n >= PREC`, where it is not known.
+    pub fn coefficient(&self, n: usize) -> R {
+        assert!(n < PREC, "the coefficient of x^{n} is beyond the precision {PREC}");
+        self.coefficients.get(n).cloned().unwrap_or_else(R::zero)
+    }
+    /// The polynomial made of the known coefficients.
+    pub fn to_polynomial(&self) -> Polynomial<R, O> {
+        Polynomial::from_coefficients(self.coefficients.clone())
+    }
+    /// The formal derivative. Its coefficient of `x^(PREC-1)` is not known and is set to zero.
+    pub fn derivative(&self) -> Self {
+        Self::from_coefficients(self.to_polynomial().derivative().coefficients)
+    }
+    fn add(self, other: &Self) -> Self {
+        Self::from_coefficients(self.to_polynomial().plus(&other.to_polynomial()).coefficients)
+    }
+    fn mul(self, other: &Self) -> Self {
+        Self::from_coefficients(mul_truncated(&self.coefficients, &other.coefficients, PREC))
+    }
+    fn negated(self) -> Self {
+        PowerSeries { coefficients: self.coefficients.into_iter().map(R::negated).collect(), o: PhantomData }
+    }
+}
+impl<R, O, const PREC: usize> PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + TryInverse<O>,
+{
+    /// The multiplicative inverse, by Newton iteration, or `None` unless the constant term is a
+    /// unit. Only `O(log PREC)` truncated products are needed.
+    pub fn reciprocal(&self) -> Option<Self> {
+        inverse_truncated(&self.coefficients, PREC).map(Self::from_coefficients)
+    }
+}
+/// A power series is a unit exactly when its constant term is.
+impl<R, O, const PREC: usize> TryInverse<SeriesOps<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O> + TryInverse<O>,
+{
+    fn try_inverse(self) -> Option<Self> {
+        self.reciprocal()
+    }
+}
+impl<F, O, const PREC: usize> PowerSeries<F, O, PREC>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// `1 / n` in `F`. Panics if `n` is zero in `F`, which happens when the characteristic is at
+    /// most `PREC`.
+    fn reciprocal_of(n: usize) -> F {
+        let n = F::from_integer(n as u64);
+        assert!(n != F::zero(), "the characteristic must be greater than the precision {PREC}");
+        n.reciprocal()
+    }
+    /// The formal integral with constant term zero. The term of `x^PREC` is dropped.
+    pub fn integral(&self) -> Self {
+        Self::from_coefficients(
+            std::iter::once(F::zero())
+                .chain(self.coefficients.iter().enumerate().map(|(k, c)| c.clone().times(&Self::reciprocal_of(k + 1))))
+                .collect(),
+        )
+    }
+    /// `log(self) = integral(self' / self)`. Panics unless the constant term is one.
+    pub fn log(&self) -> Self {
+        assert!(self.coefficient(0) == F::one(), "log needs constant term one");
+        self.derivative().times(&self.reciprocal().unwrap()).integral()
+    }
+    /// `exp(self)`, by Newton iteration on `log(g) = self`: `g <- g (1 - log(g) + self)`.
+    /// Panics unless the constant term is zero.
+    pub fn exp(&self) -> Self {
+        assert!(self.coefficient(0) == F::zero(), "exp needs constant term zero");
+        let mut g = vec![F::one()];
+        let mut k = 1;
+        while k < PREC {
+            k = (2 * k).min(PREC);
+            let log_g = Self::from_coefficients(g.clone()).log();
+            let e = constant_minus(F::one(), &log_g.minus(self).coefficients);
+            g = mul_truncated(&g, &e, k);
+        }
+        Self::from_coefficients(g)
+    }
+    /// The square root with constant term one, by Newton iteration `g <- (g + self / g) / 2`.
+    /// Panics unless the constant term is one.
+    pub fn sqrt(&self) -> Self {
+        assert!(self.coefficient(0) == F::one(), "sqrt needs constant term one");
+        let half = Self::reciprocal_of(2);
+        let mut g = vec![F::one()];
+        let mut k = 1;
+        while k < PREC {
+            k = (2 * k).min(PREC);
+            let quotient = mul_truncated(&self.coefficients, &inverse_truncated(&g, k).unwrap(), k);
+            let sum = Polynomial::<F, O>::from_coefficients(g).plus(&Polynomial::from_coefficients(quotient));
+            g = sum.coefficients.into_iter().map(|c| c.times(&half)).collect();
+        }
+        Self::from_coefficients(g)
+    }
+    /// `self^a = exp(a log(self))` for any `a` in `F`. Panics unless the constant term is one.
+    pub fn pow_field(&self, a: &F) -> Self {
+        self.log().times(&Self::constant(a.clone())).exp()
+    }
+}
+impl<R, O, const PREC: usize> From<Polynomial<R, O>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn from(f: Polynomial<R, O>) -> Self {
+        Self::from_coefficients(f.coefficients)
+    }
+}
+impl<R, O, const PREC: usize> Add<PowerSeries<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+impl<R, O, const PREC: usize> Mul<PowerSeries<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+pub struct SeriesPlus<R, O, const PREC: usize>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+pub struct SeriesTimes<R, O, const PREC: usize>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O, const PREC: usize> O2<PowerSeries<R, O, PREC>> for SeriesPlus<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(PowerSeries<R, O, PREC>, &PowerSeries<R, O, PREC>) -> PowerSeries<R, O, PREC> = <PowerSeries<R, O, PREC>>::add;
+}
+impl<R, O, const PREC: usize> O2<PowerSeries<R, O, PREC>> for SeriesTimes<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(PowerSeries<R, O, PREC>, &PowerSeries<R, O, PREC>) -> PowerSeries<R, O, PREC> = <PowerSeries<R, O, PREC>>::mul;
+}
+impl<R, O, const PREC: usize> PartialEq for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients == other.coefficients
+    }
+}
+impl<R, O, const PREC: usize> Eq for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O, const PREC: usize> Clone for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn clone(&self) -> Self {
+        PowerSeries { coefficients: self.coefficients.clone(), o: PhantomData }
+    }
+}
+impl<R, O, const PREC: usize> Set for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O, const PREC: usize> Monoid<SeriesTimes<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+impl<R, O, const PREC: usize> Monoid<SeriesPlus<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+impl<R, O, const PREC: usize> Group<SeriesPlus<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn inverse(self) -> Self {
+        self.negated()
+    }
+}
+pub struct SeriesOps<R, O, const PREC: usize>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O, const PREC: usize> RingOperations<PowerSeries<R, O, PREC>> for SeriesOps<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type PLUS = SeriesPlus<R, O, PREC>;
+    type TIMES = SeriesTimes<R, O, PREC>;
+}
+impl<R, O, const PREC: usize> Ring<SeriesOps<R, O, PREC>> for PowerSeries<R, O, PREC>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
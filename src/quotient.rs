@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
 
 use crate::{
     operation::{O2},
@@ -7,7 +8,9 @@ use crate::{
     structure::{
         group::{Group, NormalSubgroup, Subgroup},
         monoid::{Monoid, AbsorbingSubset}, ring::{Ideal, RingOperations, Ring},
-    }, wrapper::Wrapper, modular::Multiples, impls::StandardOps,
+        field::Field, euclidean_ring::EuclideanRing,
+    }, wrapper::Wrapper, modular::Multiples, impls::{StandardOps, Times}, nonzero::NonZero,
+    arithmetic::is_prime, polynomial::Polynomial, unit::TryInverse,
 };
 
 #[derive(PartialEq, Eq, Clone)]
@@ -16,6 +19,27 @@ impl<const N: i64> Wrapper<i64> for IntWrap<N> {
     const VAL: fn()->i64 = || N;
 }
 pub type IntMultiples<const N:i64>=Multiples<i64,StandardOps,IntWrap<N>>;
+/// Wrappers whose value is a prime `p`, so that the integers mod `p` are a field.
+pub trait PrimeModulus: Wrapper<i64> + 'static {
+
+}
+/// The prime `P` as a `Wrapper`. It does not compile unless `P` is prime.
+#[derive(PartialEq, Eq, Clone)]
+pub struct PrimeWrap<const P: i64> {}
+impl<const P: i64> Wrapper<i64> for PrimeWrap<P> {
+    const VAL: fn()->i64 = || {
+        const { assert!(P > 0 && is_prime(P as u64), "the modulus must be prime") };
+        P
+    };
+}
+impl<const P: i64> PrimeModulus for PrimeWrap<P> {
+
+}
+/// The integers mod `A::VAL()`.
+pub type Residues<A>=QuotientRing<i64,StandardOps,Multiples<i64,StandardOps,A>>;
+/// The field with `P` elements, for a prime `P`; a composite `P` is rejected at compile time.
+/// The integers mod any `N` are `QuotientRing<i64, StandardOps, IntMultiples<N>>`.
+pub type IntegersMod<const P:i64>=Residues<PrimeWrap<P>>;
 pub struct QuotientGroup<G, H, Op>
 where
     G: Group<Op>,
@@ -178,10 +202,65 @@ impl<R:Ring<O>,O:RingOperations<R>,I:Ideal<R,O>> Monoid<QuotientProduct<O::TIMES
     }
 }
 impl<R:Ring<O>,O:RingOperations<R>,I:Ideal<R,O>> Ring<O> for QuotientRing<R,O,I> where O::PLUS:O2<I>{
-
+    //The ideal may know a faster product, like the NTT mod a suitable prime. Failing that, Toom-3
+    //needs 2 and 3 to be units, which only the ideal can tell.
+    fn polynomial_product(a:&[Self], b:&[Self]) -> Vec<Self> {
+        let representatives = |v:&[Self]| v.iter().map(|c| c.representative.clone()).collect::<Vec<R>>();
+        if let Some(c) = I::polynomial_product(&representatives(a), &representatives(b)) {
+            return c.into_iter().map(Self::from).collect();
+        }
+        let inverse = |n| I::inverse_modulo(&R::from_integer(n)).map(Self::from);
+        let inverses = inverse(2).zip(inverse(3));
+        Polynomial::product(a, b, inverses.as_ref())
+    }
+}
+//Units mod any N are found by Bézout. This is what polynomials over the integers mod N need to
+//divide by a monic modulus; the fields IntegersMod<P> already have TryInverse as EuclideanRings.
+impl<const N:i64> TryInverse<StandardOps> for QuotientRing<i64,StandardOps,IntMultiples<N>> {
+    fn try_inverse(self)->Option<Self> {
+        IntMultiples::<N>::inverse_modulo(&self.representative).map(Self::from)
+    }
 }
 impl<R:Ring<O>,O:RingOperations<R>,I:Ideal<R,O>> Debug for QuotientRing<R,O,I> where O::PLUS:O2<I>,R:Debug{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[{:?}]", self.representative)
     }
 }
+//The representative is reduced by the ideal, so for IntegersMod<N> this prints a number in 0..N.
+impl<R:Ring<O>,O:RingOperations<R>,I:Ideal<R,O>> Display for QuotientRing<R,O,I> where O::PLUS:O2<I>,R:Display{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.representative)
+    }
+}
+impl<R:Ring<O>,O:RingOperations<R>,I:Ideal<R,O>> FromStr for QuotientRing<R,O,I> where O::PLUS:O2<I>,R:FromStr{
+    type Err = R::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<R>().map(Self::from)
+    }
+}
+
+impl<A:Wrapper<i64>+'static> Residues<A> {
+    /// The representative of this residue class in `0..A::VAL()`.
+    pub fn residue(&self) -> i64 {
+        self.representative.rem_euclid(A::VAL())
+    }
+}
+//A prime modulus leaves no zero divisors, so products of nonzero classes are nonzero.
+impl<A:PrimeModulus> O2<NonZero<Residues<A>,StandardOps>> for QuotientProduct<Times> {
+    const F: fn(NonZero<Residues<A>,StandardOps>, &NonZero<Residues<A>,StandardOps>) -> NonZero<Residues<A>,StandardOps> =
+        |a,b| Subset::try_from(a.inclusion().times(&b.clone().inclusion()));
+}
+impl<A:PrimeModulus> Monoid<QuotientProduct<Times>> for NonZero<Residues<A>,StandardOps> {
+    fn identity() -> Self {
+        Subset::try_from(Residues::one())
+    }
+}
+impl<A:PrimeModulus> Group<QuotientProduct<Times>> for NonZero<Residues<A>,StandardOps> {
+    fn inverse(self) -> Self {
+        let (x,_) = i64::bézout(self.inclusion().residue(),A::VAL());
+        <Self as Subset<Residues<A>>>::try_from(Residues::from(x))
+    }
+}
+impl<A:PrimeModulus> Field<StandardOps> for Residues<A> {
+
+}
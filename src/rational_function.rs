@@ -0,0 +1,352 @@
+use std::{fmt::{self, Debug, Display}, marker::PhantomData, ops::{Add, Mul}};
+use crate::{
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::Polynomial,
+    power_series::PowerSeries,
+    set::{Set, Subset},
+    structure::{euclidean_ring::EuclideanRing, field::Field, group::Group, monoid::Monoid, ring::{Ring, RingOperations}},
+};
+
+/// A polynomial part, together with the numerators over each power of each factor of the
+/// denominator; see [`RationalFunction::partial_fractions`].
+pub type PartialFractions<F, O> = (Polynomial<F, O>, Vec<Vec<Polynomial<F, O>>>);
+
+/// A quotient `numerator / denominator` of polynomials over a field, the field of fractions of
+/// `F[x]`. It is kept in lowest terms with a monic denominator, so equal functions have equal
+/// numerators and denominators; zero is `0 / 1`.
+pub struct RationalFunction<F, O: RingOperations<F>>
+where
+    F: Ring<O>,
+{
+    numerator: Polynomial<F, O>,
+    denominator: Polynomial<F, O>,
+}
+impl<F, O> RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    /// Divides out the gcd and makes the denominator monic. Panics if the denominator is zero.
+    pub fn new(numerator: Polynomial<F, O>, denominator: Polynomial<F, O>) -> Self {
+        assert!(denominator != Polynomial::zero(), "zero denominator");
+        let g = EuclideanRing::gcd(numerator.clone(), denominator.clone());
+        let (numerator, denominator) = (numerator.quotient(&g), denominator.quotient(&g));
+        let unit = Polynomial::constant(denominator.lead_coeff().reciprocal());
+        RationalFunction { numerator: numerator.times(&unit), denominator: denominator.times(&unit) }
+    }
+    pub fn zero() -> Self {
+        Self::from(Polynomial::zero())
+    }
+    pub fn one() -> Self {
+        Self::from(Polynomial::one())
+    }
+    pub fn x() -> Self {
+        Self::from(Polynomial::x())
+    }
+    pub fn constant(c: F) -> Self {
+        Self::from(Polynomial::constant(c))
+    }
+    pub fn numerator(&self) -> &Polynomial<F, O> {
+        &self.numerator
+    }
+    /// Monic, and coprime to the numerator.
+    pub fn denominator(&self) -> &Polynomial<F, O> {
+        &self.denominator
+    }
+    /// The value at `x`, or `None` at a pole, where the denominator vanishes.
+    pub fn of(&self, x: F) -> Option<F> {
+        let d = self.denominator.of(x.clone());
+        if d == F::zero() {
+            return None;
+        }
+        Some(self.numerator.of(x).times(&d.reciprocal()))
+    }
+    /// `(n / d)' = (n' d - n d') / d^2`.
+    pub fn derivative(&self) -> Self {
+        let (n, d) = (&self.numerator, &self.denominator);
+        let top = n.derivative().times(d).minus(&n.clone().times(&d.derivative()));
+        Self::new(top, d.clone().times(d))
+    }
+    /// The first `PREC` coefficients of the power series at zero, as for a generating function, or
+    /// `None` if zero is a pole.
+    pub fn expand<const PREC: usize>(&self) -> Option<PowerSeries<F, O, PREC>> {
+        let d = PowerSeries::<F, O, PREC>::from(self.denominator.clone());
+        Some(PowerSeries::from(self.numerator.clone()).times(&d.reciprocal()?))
+    }
+    /// The partial fraction decomposition `q + sum_i sum_j a_ij / p_i^j`, for `j` from 1 to `e_i`,
+    /// with `deg a_ij < deg p_i`, given the denominator as a product of pairwise coprime powers
+    /// `p_i^e_i`, such as its factorization or square-free decomposition. Returns `q` and, for each
+    /// `(p_i, e_i)`, the numerators `[a_i1, ..., a_ie_i]`.
+    ///
+    /// The proper part `r / d` splits as `sum c_i / p_i^e_i` with `c_i = r (d / p_i^e_i)^-1` mod
+    /// `p_i^e_i`, and then the digits of `c_i` in base `p_i` are the `a_ij`. Panics if the factors do
+    /// not multiply to the denominator up to a constant, or are not pairwise coprime.
+    pub fn partial_fractions(&self, factors: &[(Polynomial<F, O>, usize)]) -> PartialFractions<F, O> {
+        let powers: Vec<Polynomial<F, O>> = factors.iter().map(|(p, e)| Ring::pow(p.clone(), *e as u64)).collect();
+        let product = powers.iter().fold(Polynomial::one(), |a, b| a.times(b));
+        assert!(product.normalized() == self.denominator, "the factors must multiply to the denominator");
+        let (q, r) = Polynomial::divide(self.numerator.clone(), &self.denominator);
+        let parts = factors
+            .iter()
+            .zip(&powers)
+            .map(|((p, e), power)| {
+                let cofactor = self.denominator.clone().quotient(power);
+                let (s, t) = EuclideanRing::bézout(cofactor.clone(), power.clone());
+                let g = s.clone().times(&cofactor).plus(&t.times(power));
+                assert!(g.degree() == 0, "the factors must be pairwise coprime");
+                let inverse = s.times(&Polynomial::constant(g.lead_coeff().reciprocal()));
+                let mut c = r.clone().times(&inverse).remainder(power);
+                let mut digits = Vec::with_capacity(*e);
+                for _ in 0..*e {
+                    let (next, digit) = Polynomial::divide(c, p);
+                    digits.push(digit);
+                    c = next;
+                }
+                // c = a_1 p^(e-1) + ... + a_e, so the lowest digit goes with the highest power.
+                digits.reverse();
+                digits
+            })
+            .collect();
+        (q, parts)
+    }
+    fn add(self, other: &Self) -> Self {
+        let n = self.numerator.times(&other.denominator).plus(&other.numerator.clone().times(&self.denominator));
+        Self::new(n, self.denominator.times(&other.denominator))
+    }
+    fn mul(self, other: &Self) -> Self {
+        Self::new(self.numerator.times(&other.numerator), self.denominator.times(&other.denominator))
+    }
+    fn negated(self) -> Self {
+        RationalFunction { numerator: Ring::negated(self.numerator), denominator: self.denominator }
+    }
+}
+impl<F, O> From<Polynomial<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn from(f: Polynomial<F, O>) -> Self {
+        RationalFunction { numerator: f, denominator: Polynomial::one() }
+    }
+}
+/// Like `Polynomial`, in the variable `x`: `(x + 1)/(x^2 + 2)`, or just the numerator when the
+/// denominator is one.
+impl<F, O> Display for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O> + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == Polynomial::one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "({})/({})", self.numerator, self.denominator)
+        }
+    }
+}
+impl<F, O> Debug for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?})/({:?})", self.numerator, self.denominator)
+    }
+}
+impl<F, O> Add<RationalFunction<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+impl<F, O> Mul<RationalFunction<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+pub struct RationalPlus<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    f: PhantomData<F>,
+    o: PhantomData<O>,
+}
+pub struct RationalTimes<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    f: PhantomData<F>,
+    o: PhantomData<O>,
+}
+impl<F, O> O2<RationalFunction<F, O>> for RationalPlus<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    const F: fn(RationalFunction<F, O>, &RationalFunction<F, O>) -> RationalFunction<F, O> = <RationalFunction<F, O>>::add;
+}
+impl<F, O> O2<RationalFunction<F, O>> for RationalTimes<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    const F: fn(RationalFunction<F, O>, &RationalFunction<F, O>) -> RationalFunction<F, O> = <RationalFunction<F, O>>::mul;
+}
+impl<F, O> PartialEq for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.numerator == other.numerator && self.denominator == other.denominator
+    }
+}
+impl<F, O> Eq for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+}
+impl<F, O> Clone for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    fn clone(&self) -> Self {
+        RationalFunction { numerator: self.numerator.clone(), denominator: self.denominator.clone() }
+    }
+}
+impl<F, O> Set for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+}
+impl<F, O> Monoid<RationalTimes<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+impl<F, O> Monoid<RationalPlus<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+impl<F, O> Group<RationalPlus<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn inverse(self) -> Self {
+        self.negated()
+    }
+}
+pub struct RationalOps<F, O>
+where
+    O: RingOperations<F>,
+    F: Ring<O>,
+{
+    f: PhantomData<F>,
+    o: PhantomData<O>,
+}
+impl<F, O> RingOperations<RationalFunction<F, O>> for RationalOps<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    type PLUS = RationalPlus<F, O>;
+    type TIMES = RationalTimes<F, O>;
+}
+impl<F, O> Ring<RationalOps<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+}
+//F[x] has no zero divisors, so neither does its field of fractions.
+impl<F, O> O2<NonZero<RationalFunction<F, O>, RationalOps<F, O>>> for RationalTimes<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    const F: fn(
+        NonZero<RationalFunction<F, O>, RationalOps<F, O>>,
+        &NonZero<RationalFunction<F, O>, RationalOps<F, O>>,
+    ) -> NonZero<RationalFunction<F, O>, RationalOps<F, O>> = |a, b| Subset::try_from(a.inclusion().times(&b.clone().inclusion()));
+}
+impl<F, O> Monoid<RationalTimes<F, O>> for NonZero<RationalFunction<F, O>, RationalOps<F, O>>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn identity() -> Self {
+        Subset::try_from(RationalFunction::one())
+    }
+}
+//The inverse of n / d is d / n, which new renormalizes so the denominator is monic.
+impl<F, O> Group<RationalTimes<F, O>> for NonZero<RationalFunction<F, O>, RationalOps<F, O>>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn inverse(self) -> Self {
+        let r = self.inclusion();
+        <Self as Subset<RationalFunction<F, O>>>::try_from(RationalFunction::new(r.denominator, r.numerator))
+    }
+}
+impl<F, O> Field<RationalOps<F, O>> for RationalFunction<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+}
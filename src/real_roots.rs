@@ -0,0 +1,237 @@
+use std::{cmp::Ordering, fmt::{self, Display}};
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+use crate::{impls::StandardOps, polynomial::Polynomial, structure::{euclidean_ring::EuclideanRing, ring::Ring}};
+
+type ZX = Polynomial<BigInt, StandardOps>;
+
+/// An exact rational number `numerator / denominator`, in lowest terms with a positive
+/// denominator. Used for the endpoints of root-isolating intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    pub numerator: BigInt,
+    pub denominator: BigInt,
+}
+impl Rational {
+    /// Panics if the denominator is zero.
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        assert!(!denominator.is_zero(), "zero denominator");
+        let g = BigInt::gcd(numerator.clone(), denominator.clone()).abs();
+        let g = if denominator.is_negative() { -g } else { g };
+        Rational { numerator: numerator / &g, denominator: denominator / g }
+    }
+    pub fn integer(n: BigInt) -> Self {
+        Rational { numerator: n, denominator: BigInt::from(1) }
+    }
+}
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::integer(BigInt::from(n))
+    }
+}
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator.is_one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// The sign of `f(r)`, from the integer `q^n f(p/q)`, which has the same sign since `q > 0`.
+fn sign_at(f: &ZX, r: &Rational) -> Ordering {
+    let mut value = BigInt::from(0);
+    let mut q_power = BigInt::from(1);
+    for c in f.coefficients.iter().rev() {
+        value = value * &r.numerator + c * &q_power;
+        q_power *= &r.denominator;
+    }
+    value.sign_cmp()
+}
+/// The sign of `f(x)` for all large enough positive `x`, or negative `x` if `negative`.
+fn sign_at_infinity(f: &ZX, negative: bool) -> Ordering {
+    let lc = f.lead_coeff().sign_cmp();
+    if negative && f.degree().unwrap() % 2 == 1 {
+        lc.reverse()
+    } else {
+        lc
+    }
+}
+trait SignCmp {
+    fn sign_cmp(&self) -> Ordering;
+}
+impl SignCmp for BigInt {
+    fn sign_cmp(&self) -> Ordering {
+        self.cmp(&BigInt::from(0))
+    }
+}
+/// The number of sign changes in a sequence of signs, ignoring zeros.
+fn variations(signs: impl Iterator<Item = Ordering>) -> usize {
+    let mut previous = Ordering::Equal;
+    let mut count = 0;
+    for s in signs.filter(|s| s.is_ne()) {
+        if previous.is_ne() && s != previous {
+            count += 1;
+        }
+        previous = s;
+    }
+    count
+}
+/// Divides out the positive gcd of the coefficients, which leaves all signs alone.
+fn positive_primitive_part(f: ZX) -> ZX {
+    let g = f.coefficients.iter().fold(BigInt::from(0), |a, c| BigInt::gcd(a, c.clone())).abs();
+    if g.is_zero() {
+        return f;
+    }
+    ZX::from_coefficients(f.coefficients.into_iter().map(|c| c / &g).collect())
+}
+
+impl Polynomial<BigInt, StandardOps> {
+    /// The part of `self` without repeated factors, `self / gcd(self, self')`, which has the same
+    /// real roots but each only once.
+    fn square_free_part(&self) -> Self {
+        let g = Self::subresultant_gcd(self, &self.derivative());
+        self.pquo(&g).primitive_part()
+    }
+    /// The Sturm sequence `p_0 = f`, `p_1 = f'`, `p_(k+1) = -(p_(k-1) mod p_k)`, ending with a
+    /// constant multiple of `gcd(f, f')`. Each term is computed by pseudo-division and then
+    /// divided by a positive constant, which keeps it over the integers without changing any sign.
+    pub fn sturm_sequence(&self) -> Vec<Self> {
+        let mut sequence = vec![self.clone()];
+        let mut next = self.derivative();
+        while next != Self::zero() {
+            let (a, b) = (sequence.last().unwrap(), &next);
+            let r = a.prem(b);
+            // prem multiplies the remainder by lc(b)^(deg a - deg b + 1).
+            let delta = a.degree().unwrap() + 1 - b.degree().unwrap();
+            let flipped = b.lead_coeff().is_negative() && delta % 2 == 1;
+            let r = if flipped { r } else { r.negated() };
+            sequence.push(next);
+            next = positive_primitive_part(r);
+        }
+        sequence
+    }
+    /// The number of distinct real roots in the half-open interval `(a, b]`, by Sturm's theorem.
+    /// Panics if `self` is zero.
+    pub fn count_real_roots_between(&self, a: &Rational, b: &Rational) -> usize {
+        if a >= b {
+            return 0;
+        }
+        let sequence = self.square_free_part().sturm_sequence();
+        variations(sequence.iter().map(|p| sign_at(p, a))) - variations(sequence.iter().map(|p| sign_at(p, b)))
+    }
+    /// The number of distinct real roots. Panics if `self` is zero.
+    pub fn count_real_roots(&self) -> usize {
+        let sequence = self.square_free_part().sturm_sequence();
+        variations(sequence.iter().map(|p| sign_at_infinity(p, true)))
+            - variations(sequence.iter().map(|p| sign_at_infinity(p, false)))
+    }
+    /// Isolating intervals for the distinct real roots, in increasing order. Each is either an
+    /// open interval `(a, b)` containing exactly one root, or a single point `(a, a)` that is a
+    /// root. The endpoints are dyadic rationals. Panics if `self` is zero.
+    ///
+    /// All real roots lie in `(-B, B)` for a power of two `B` above the Cauchy bound, which is
+    /// mapped onto `(0, 1)`. Intervals are then bisected until Descartes' rule of signs, applied to
+    /// the Möbius transform that sends the interval to `(0, ∞)`, shows that they contain at most
+    /// one root: the Vincent–Collins–Akritas method.
+    pub fn isolate_real_roots(&self) -> Vec<(Rational, Rational)> {
+        assert!(!self.coefficients.is_empty(), "the zero polynomial has every real number as a root");
+        let f = self.square_free_part();
+        let n = f.degree().unwrap();
+        if n == 0 {
+            return vec![];
+        }
+        // Cauchy: every root has |x| < 1 + max |a_i / a_n| <= 2^m.
+        let lc = f.lead_coeff().abs();
+        let max = f.coefficients.iter().map(|c| c.abs()).max().unwrap();
+        let m = ((max + &lc - 1u32) / &lc + 1u32).bits();
+        let b = BigInt::from(1) << m;
+        // g(t) = f(2B t - B), whose roots in (0, 1) correspond to those of f.
+        let shifted = f.taylor_shift(&-&b);
+        let mut scale = BigInt::from(1);
+        let g = ZX::from_coefficients(
+            shifted
+                .coefficients
+                .into_iter()
+                .map(|c| {
+                    let c = c * &scale;
+                    scale <<= m + 1;
+                    c
+                })
+                .collect(),
+        );
+        let mut intervals = vec![];
+        isolate_in_unit_interval(g, BigInt::from(0), 0, &mut intervals);
+        // t = c / 2^k corresponds to x = (c 2^(m+1) - 2^(m+k)) / 2^k.
+        let to_x = |c: &BigInt, k: u64| Rational::new((c << (m + 1)) - (BigInt::from(1) << (m + k)), BigInt::from(1) << k);
+        intervals
+            .into_iter()
+            .map(|(c, k, exact)| {
+                let lower = to_x(&c, k);
+                let upper = if exact { lower.clone() } else { to_x(&(c + 1), k) };
+                (lower, upper)
+            })
+            .collect()
+    }
+}
+/// Isolates the roots of `p` in `[0, 1)`, where `p` is the transform of the original polynomial
+/// for the interval `[c / 2^k, (c + 1) / 2^k)`. Pushes `(c, k, true)` for a root at the left
+/// endpoint and `(c, k, false)` for an open interval with exactly one root, in increasing order.
+fn isolate_in_unit_interval(mut p: ZX, c: BigInt, k: u64, out: &mut Vec<(BigInt, u64, bool)>) {
+    if p.coefficients[0].is_zero() {
+        out.push((c.clone(), k, true));
+        p = ZX::from_coefficients(p.coefficients[1..].to_vec());
+    }
+    let n = p.degree().unwrap();
+    if n == 0 {
+        return;
+    }
+    // Roots in (0, 1) are the positive roots of (x + 1)^n p(1 / (x + 1)).
+    let mut reversed = p.coefficients.clone();
+    reversed.reverse();
+    let transformed = ZX::from_coefficients(reversed).taylor_shift(&BigInt::from(1));
+    match variations(transformed.coefficients.iter().map(SignCmp::sign_cmp)) {
+        0 => {}
+        1 => out.push((c, k, false)),
+        _ => {
+            // 2^n p(x / 2) for [0, 1/2), and its shift by one for [1/2, 1).
+            let left = ZX::from_coefficients(
+                p.coefficients.iter().enumerate().map(|(i, a)| a << (n - i)).collect(),
+            );
+            let right = left.taylor_shift(&BigInt::from(1));
+            let c: BigInt = c << 1u32;
+            isolate_in_unit_interval(left, c.clone(), k + 1, out);
+            isolate_in_unit_interval(right, c + 1, k + 1, out);
+        }
+    }
+}
+impl Polynomial<i64, StandardOps> {
+    fn to_big(&self) -> ZX {
+        ZX::from_coefficients(self.coefficients.iter().map(|c| BigInt::from(*c)).collect())
+    }
+    /// See the `BigInt` version.
+    pub fn count_real_roots_between(&self, a: &Rational, b: &Rational) -> usize {
+        self.to_big().count_real_roots_between(a, b)
+    }
+    /// See the `BigInt` version.
+    pub fn count_real_roots(&self) -> usize {
+        self.to_big().count_real_roots()
+    }
+    /// See the `BigInt` version.
+    pub fn isolate_real_roots(&self) -> Vec<(Rational, Rational)> {
+        self.to_big().isolate_real_roots()
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    polynomial::{Degree, Polynomial},
+    structure::{
+        euclidean_ring::EuclideanRing,
+        ring::{Ring, RingOperations},
+    },
+};
+
+/// The determinant of a square matrix over a commutative ring, by Berkowitz's algorithm. It uses
+/// `O(n^4)` ring operations and no divisions, so unlike Gaussian elimination it works over rings
+/// with zero divisors.
+pub fn determinant<R: Ring<O>, O: RingOperations<R>>(m: &[Vec<R>]) -> R {
+    // v holds the characteristic polynomial of the leading r×r block, highest degree first.
+    let mut v = vec![R::one()];
+    for r in 0..m.len() {
+        let mut t = vec![R::one(), m[r][r].clone().negated()];
+        let mut w: Vec<R> = (0..r).map(|i| m[i][r].clone()).collect();
+        for _ in 0..r {
+            let dot = (0..r).fold(R::zero(), |s, j| s.plus(&m[r][j].clone().times(&w[j])));
+            t.push(dot.negated());
+            w = (0..r)
+                .map(|i| (0..r).fold(R::zero(), |s, j| s.plus(&m[i][j].clone().times(&w[j]))))
+                .collect();
+        }
+        v = (0..r + 2)
+            .map(|i| {
+                (0..=i.min(r)).fold(R::zero(), |s, j| s.plus(&t[i - j].clone().times(&v[j])))
+            })
+            .collect();
+    }
+    let det = v.pop().unwrap();
+    if m.len().is_multiple_of(2) {
+        det
+    } else {
+        det.negated()
+    }
+}
+
+impl<R: Ring<O>, O: RingOperations<R>> Polynomial<R, O> {
+    /// The Sylvester matrix of `f` and `g`: `deg g` shifted copies of the coefficients of `f`
+    /// followed by `deg f` shifted copies of those of `g`, highest degree first. Panics if either
+    /// polynomial is zero.
+    pub fn sylvester_matrix(f: &Self, g: &Self) -> Vec<Vec<R>> {
+        let (m, n) = (f.degree().unwrap(), g.degree().unwrap());
+        let row = |p: &Self, shift: usize| {
+            let mut r = vec![R::zero(); m + n];
+            for (i, c) in p.coefficients.iter().rev().enumerate() {
+                r[shift + i] = c.clone();
+            }
+            r
+        };
+        (0..n).map(|i| row(f, i)).chain((0..m).map(|i| row(g, i))).collect()
+    }
+    /// The resultant of `f` and `g` as the determinant of their Sylvester matrix. Works over any
+    /// commutative ring, but takes `O((m+n)^4)` ring operations; see [`Polynomial::resultant`]
+    /// for a faster method over GCD domains. The resultant with the zero polynomial is zero.
+    pub fn sylvester_resultant(f: &Self, g: &Self) -> R {
+        if f.coefficients.is_empty() || g.coefficients.is_empty() {
+            return R::zero();
+        }
+        determinant(&Self::sylvester_matrix(f, g))
+    }
+}
+
+impl<R: EuclideanRing<O>, O: RingOperations<R>> Polynomial<R, O> {
+    /// The resultant of `f` and `g`, by the subresultant algorithm. Agrees with
+    /// [`Polynomial::sylvester_resultant`], and is zero exactly when `f` and `g` have a common
+    /// factor of positive degree. Needs exact division, so the coefficients must form a GCD
+    /// domain.
+    pub fn resultant(f: &Self, g: &Self) -> R {
+        let (mut a, mut b) = match (f.degree(), g.degree()) {
+            (Degree::NegInfty, _) | (_, Degree::NegInfty) => return R::zero(),
+            (d, e) if d >= e => (f.clone(), g.clone()),
+            _ => (g.clone(), f.clone()),
+        };
+        let minus_one = R::one().negated();
+        let odd = |p: &Self| p.degree().unwrap() % 2 == 1;
+        let mut s = if odd(&a) && odd(&b) && f.degree() < g.degree() {
+            minus_one.clone()
+        } else {
+            R::one()
+        };
+        let (ca, cb) = (a.content(), b.content());
+        let t = Ring::pow(ca, b.degree().unwrap() as u64)
+            .times(&Ring::pow(cb.clone(), a.degree().unwrap() as u64));
+        if b.degree() == 0 {
+            return s.times(&t);
+        }
+        a = a.primitive_part();
+        b = b.primitive_part();
+        let mut lc_power = R::one();
+        let mut h = R::one();
+        while b.degree() > 0 {
+            let delta = a.degree().unwrap() - b.degree().unwrap();
+            if odd(&a) && odd(&b) {
+                s = s.times(&minus_one);
+            }
+            let r = a.prem(&b);
+            a = b;
+            let divisor = lc_power.times(&Ring::pow(h.clone(), delta as u64));
+            b = Polynomial {
+                coefficients: r.coefficients.into_iter().map(|c| c.quotient(&divisor)).collect(),
+                o: a.o,
+            };
+            lc_power = a.lead_coeff();
+            if delta > 0 {
+                h = Ring::pow(lc_power.clone(), delta as u64)
+                    .quotient(&Ring::pow(h, delta as u64 - 1));
+            }
+        }
+        if b.coefficients.is_empty() {
+            return R::zero();
+        }
+        let d = a.degree().unwrap() as u64;
+        let h = Ring::pow(b.lead_coeff(), d).quotient(&Ring::pow(h, d - 1));
+        s.times(&t).times(&h)
+    }
+    /// The discriminant `(-1)^(n(n-1)/2) res(f, f') / lc(f)` of a polynomial of degree `n`. It
+    /// vanishes exactly when `f` has a repeated factor, provided the characteristic does not
+    /// divide `n`. Panics if `f` is constant.
+    pub fn discriminant(&self) -> R {
+        let n = self.degree().unwrap();
+        assert!(n > 0, "constant polynomials have no discriminant");
+        let d = Self::resultant(self, &self.derivative()).quotient(&self.lead_coeff());
+        if (n * (n - 1) / 2).is_multiple_of(2) {
+            d
+        } else {
+            d.negated()
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::{cmp::Reverse, collections::{BTreeMap, BinaryHeap}, marker::PhantomData, ops::{Add, Mul}};
+use crate::{
+    modular::{euclidean_divides, Divisibility},
+    nonzero::NonZero,
+    operation::O2,
+    polynomial::{Degree, Polynomial},
+    set::Set,
+    structure::{euclidean_ring::EuclideanRing, field::Field, group::Group, monoid::Monoid, ring::{Ring, RingOperations}},
+    unit::TryInverse,
+};
+
+/// A univariate polynomial stored as its nonzero terms `(exponent, coefficient)`, in increasing
+/// order of exponent. Memory and time depend on the number of terms rather than the degree, so
+/// `x^1000000 + 1` takes two terms where the dense `Polynomial` takes a million coefficients.
+pub struct SparsePolynomial<R, O: RingOperations<R>>
+where
+    R: Ring<O>,
+{
+    pub terms: Vec<(usize, R)>,
+    pub o: PhantomData<O>,
+}
+impl<R, O> SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    /// Sums the given terms, which may come in any order and repeat exponents.
+    pub fn from_terms(terms: impl IntoIterator<Item = (usize, R)>) -> Self {
+        let mut map: BTreeMap<usize, R> = BTreeMap::new();
+        for (e, c) in terms {
+            let sum = match map.remove(&e) {
+                Some(d) => d.plus(&c),
+                None => c,
+            };
+            map.insert(e, sum);
+        }
+        Self::from_sorted(map.into_iter().collect())
+    }
+    /// Drops zero terms from terms already sorted by exponent without repeats.
+    fn from_sorted(terms: Vec<(usize, R)>) -> Self {
+        SparsePolynomial { terms: terms.into_iter().filter(|(_, c)| c != &R::zero()).collect(), o: PhantomData }
+    }
+    pub fn zero() -> Self {
+        SparsePolynomial { terms: vec![], o: PhantomData }
+    }
+    pub fn one() -> Self {
+        Self::constant(R::one())
+    }
+    pub fn x() -> Self {
+        Self::x_pow(1)
+    }
+    pub fn constant(c: R) -> Self {
+        Self::from_sorted(vec![(0, c)])
+    }
+    pub fn x_pow(n: usize) -> Self {
+        Self::from_sorted(vec![(n, R::one())])
+    }
+    pub fn degree(&self) -> Degree {
+        match self.terms.last() {
+            Some((e, _)) => Degree::Integer(*e),
+            None => Degree::NegInfty,
+        }
+    }
+    pub fn coefficient(&self, n: usize) -> R {
+        match self.terms.binary_search_by_key(&n, |(e, _)| *e) {
+            Ok(i) => self.terms[i].1.clone(),
+            Err(_) => R::zero(),
+        }
+    }
+    /// The leading coefficient. Panics if given the zero polynomial.
+    pub fn lead_coeff(&self) -> R {
+        self.terms.last().unwrap().1.clone()
+    }
+    /// Evaluates at `x`, raising `x` only to the gaps between successive exponents.
+    pub fn of(&self, x: R) -> R {
+        let mut res = R::zero();
+        let mut previous = None;
+        for (e, c) in self.terms.iter().rev() {
+            if let Some(p) = previous {
+                res = res.times(&Ring::pow(x.clone(), (p - e) as u64));
+            }
+            res = res.plus(c);
+            previous = Some(*e);
+        }
+        match previous {
+            Some(e) => res.times(&Ring::pow(x, e as u64)),
+            None => res,
+        }
+    }
+    /// `c * x^n * self`.
+    pub fn mul_term(&self, c: &R, n: usize) -> Self {
+        Self::from_sorted(self.terms.iter().map(|(e, d)| (e + n, c.clone().times(d))).collect())
+    }
+    fn add(self, other: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let mut right = other.terms.iter().peekable();
+        for (e, c) in self.terms {
+            while let Some((f, d)) = right.next_if(|(f, _)| *f < e) {
+                terms.push((*f, d.clone()));
+            }
+            match right.next_if(|(f, _)| *f == e) {
+                Some((_, d)) => terms.push((e, c.plus(d))),
+                None => terms.push((e, c)),
+            }
+        }
+        terms.extend(right.cloned());
+        Self::from_sorted(terms)
+    }
+    /// Multiplies by merging the rows `a_i x^i * other` with a heap, so that the terms of the
+    /// product come out in order and only `O(#self)` partial sums are held at once.
+    fn mul(self, other: &Self) -> Self {
+        if self.terms.is_empty() || other.terms.is_empty() {
+            return Self::zero();
+        }
+        let mut heap: BinaryHeap<Reverse<(usize, usize, usize)>> = (0..self.terms.len())
+            .map(|i| Reverse((self.terms[i].0 + other.terms[0].0, i, 0)))
+            .collect();
+        let mut terms: Vec<(usize, R)> = vec![];
+        while let Some(Reverse((e, i, j))) = heap.pop() {
+            let c = self.terms[i].1.clone().times(&other.terms[j].1);
+            match terms.last_mut() {
+                Some((f, d)) if *f == e => take_mut::take(d, |d| d.plus(&c)),
+                _ => terms.push((e, c)),
+            }
+            if j + 1 < other.terms.len() {
+                heap.push(Reverse((self.terms[i].0 + other.terms[j + 1].0, i, j + 1)));
+            }
+        }
+        Self::from_sorted(terms)
+    }
+    fn negated(self) -> Self {
+        SparsePolynomial { terms: self.terms.into_iter().map(|(e, c)| (e, c.negated())).collect(), o: PhantomData }
+    }
+}
+impl<R: Ring<O>, O: RingOperations<R>> SparsePolynomial<R, O>
+where
+    R: TryInverse<O>,
+{
+    /// The leading coefficient of the divisor must be a unit.
+    /// Panics if this is not the case.
+    pub fn divide(dividend: Self, divisor: &Self) -> (Self, Self) {
+        let n = divisor.degree().unwrap();
+        let i = divisor.lead_coeff().try_inverse().unwrap();
+        let mut remainder: BTreeMap<usize, R> = dividend.terms.into_iter().collect();
+        let mut quotient = vec![];
+        while let Some((&m, c)) = remainder.last_key_value() {
+            if m < n {
+                break;
+            }
+            let t = c.clone().times(&i);
+            for (e, d) in &divisor.terms {
+                let k = e + m - n;
+                let c = remainder.remove(&k).unwrap_or_else(R::zero).minus(&t.clone().times(d));
+                if c != R::zero() {
+                    remainder.insert(k, c);
+                }
+            }
+            // `t` times the leading coefficient cancels `c`; removing it anyway guarantees progress.
+            remainder.remove(&m);
+            quotient.push((m - n, t));
+        }
+        quotient.reverse();
+        (Self::from_sorted(quotient), Self::from_sorted(remainder.into_iter().collect()))
+    }
+}
+impl<R, O> From<Polynomial<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn from(f: Polynomial<R, O>) -> Self {
+        Self::from_sorted(f.coefficients.into_iter().enumerate().collect())
+    }
+}
+impl<R, O> From<SparsePolynomial<R, O>> for Polynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn from(f: SparsePolynomial<R, O>) -> Self {
+        let mut coefficients = match f.degree() {
+            Degree::Integer(n) => vec![R::zero(); n + 1],
+            Degree::NegInfty => vec![],
+        };
+        for (e, c) in f.terms {
+            coefficients[e] = c;
+        }
+        Polynomial { coefficients, o: PhantomData }
+    }
+}
+impl<R, O> Add<SparsePolynomial<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+impl<R, O> Mul<SparsePolynomial<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+pub struct SPlus<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+pub struct STimes<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O> O2<SparsePolynomial<R, O>> for SPlus<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(SparsePolynomial<R, O>, &SparsePolynomial<R, O>) -> SparsePolynomial<R, O> = <SparsePolynomial<R, O>>::add;
+}
+impl<R, O> O2<SparsePolynomial<R, O>> for STimes<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    const F: fn(SparsePolynomial<R, O>, &SparsePolynomial<R, O>) -> SparsePolynomial<R, O> = <SparsePolynomial<R, O>>::mul;
+}
+impl<R, O> PartialEq for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+impl<R, O> Eq for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O> Clone for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn clone(&self) -> Self {
+        SparsePolynomial { terms: self.terms.clone(), o: PhantomData }
+    }
+}
+impl<R, O> Set for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<R, O> Monoid<STimes<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+impl<R, O> Monoid<SPlus<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+impl<R, O> Group<SPlus<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    fn inverse(self) -> Self {
+        self.negated()
+    }
+}
+pub struct SparseOps<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    r: PhantomData<R>,
+    o: PhantomData<O>,
+}
+impl<R, O> RingOperations<SparsePolynomial<R, O>> for SparseOps<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+    type PLUS = SPlus<R, O>;
+    type TIMES = STimes<R, O>;
+}
+impl<R, O> Ring<SparseOps<R, O>> for SparsePolynomial<R, O>
+where
+    O: RingOperations<R>,
+    R: Ring<O>,
+{
+}
+impl<F, O> EuclideanRing<SparseOps<F, O>> for SparsePolynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn norm(&self) -> Degree {
+        self.degree()
+    }
+    fn quotient(self, divisor: &Self) -> Self {
+        Self::divide(self, divisor).0
+    }
+    fn divide(self, divisor: &Self) -> (Self, Self) {
+        Self::divide(self, divisor)
+    }
+    fn remainder(self, divisor: &Self) -> Self {
+        Self::divide(self, divisor).1
+    }
+    fn normalized(self) -> Self {
+        match self.terms.last() {
+            Some((_, c)) => {
+                let c = c.clone().reciprocal();
+                self.mul_term(&c, 0)
+            }
+            None => self,
+        }
+    }
+}
+impl<F, O> Divisibility<SparseOps<F, O>> for SparsePolynomial<F, O>
+where
+    O: RingOperations<F>,
+    F: Field<O>,
+    O::TIMES: O2<NonZero<F, O>>,
+    NonZero<F, O>: Group<O::TIMES>,
+{
+    fn divides(g: &Self, t: &Self) -> bool {
+        euclidean_divides(g, t)
+    }
+    fn reduce_modulo(t: Self, g: &Self) -> Self {
+        t.remainder(g)
+    }
+}
@@ -1,4 +1,7 @@
 
+use num_bigint::BigInt;
+use num_traits::{Euclid, Signed, ToPrimitive};
+
 use crate::{operation::{O2}, polynomial::Degree, nonzero::NonZero, impls::StandardOps};
 
 use super::{group::{Group, Subgroup, AbelianGroup}, monoid::{Monoid, AbsorbingSubset}, ring::{Ring, RingOperations}, field::Field};
@@ -15,6 +18,13 @@ pub trait EuclideanRing<O:RingOperations<Self>>:Ring<O> {
     fn divide(self, divisor:&Self) -> (Self,Self) {
         (self.clone().quotient(divisor),self.remainder(divisor))
     }
+    //Picks a canonical element among the associates of self, so that a and b are associates
+    //exactly when a.normalized()==b.normalized(). For example the absolute value of an integer,
+    //or the monic multiple of a polynomial over a field.
+    //The default leaves self alone, which is only right when the ring has no units besides one.
+    fn normalized(self) -> Self {
+        self
+    }
     //Returns x and y such that ax+by=gcd(a,b)
     fn bézout(a:Self,b:Self)->(Self,Self){
         if b==Self::zero() {
@@ -49,6 +59,13 @@ impl<F:Field<O>,O:RingOperations<F>> EuclideanRing<O> for F where O::TIMES: O2<N
     fn remainder(self,divisor:&Self) -> Self {
         Self::zero()
     }
+    fn normalized(self) -> Self {
+        if self==F::zero() {
+            self
+        } else {
+            F::one()
+        }
+    }
 }
 impl EuclideanRing<StandardOps> for i64 {
     fn norm(&self) -> Degree {
@@ -58,6 +75,24 @@ impl EuclideanRing<StandardOps> for i64 {
         self.div_euclid(*divisor)
     }
     fn remainder(self,divisor:&Self) -> Self {
-        self%divisor
+        self.rem_euclid(*divisor)
+    }
+    fn normalized(self) -> Self {
+        self.abs()
+    }
+}
+impl EuclideanRing<StandardOps> for BigInt {
+    fn norm(&self) -> Degree {
+        //Saturates for huge values, so the norm only decreases strictly below usize::MAX.
+        Degree::Integer(self.abs().to_usize().unwrap_or(usize::MAX))
+    }
+    fn quotient(self,divisor:&Self) -> Self {
+        self.div_euclid(divisor)
+    }
+    fn remainder(self,divisor:&Self) -> Self {
+        self.rem_euclid(divisor)
+    }
+    fn normalized(self) -> Self {
+        self.abs()
     }
 }
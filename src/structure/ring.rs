@@ -1,4 +1,4 @@
-use crate::operation::{O2};
+use crate::{operation::{O2}, polynomial::Polynomial};
 
 use super::{group::{Group, Subgroup, AbelianGroup}, monoid::{Monoid, AbsorbingSubset}};
 pub trait RingOperations<T>
@@ -43,6 +43,12 @@ where
     fn pow(self, n:u64)->Self {
         <Self as Monoid<O::TIMES>>::pow(self, n)
     }
+    //The coefficients of the product of the polynomials with coefficients a and b, constant term
+    //first. Polynomial multiplication goes through this, so that a ring can multiply its own
+    //polynomials faster. The default has no inverses of 2 and 3 for Toom-3, so it uses Karatsuba.
+    fn polynomial_product(a:&[Self], b:&[Self]) -> Vec<Self> {
+        Polynomial::<Self,O>::product(a, b, None)
+    }
 }
 pub trait Ideal<R:Ring<O>,O:RingOperations<R>>: Subgroup<R,O::PLUS>+AbsorbingSubset<R,O::TIMES> where O::PLUS:O2<Self>{
     fn reduce(r: R) -> R {
@@ -52,6 +58,16 @@ pub trait Ideal<R:Ring<O>,O:RingOperations<R>>: Subgroup<R,O::PLUS>+AbsorbingSub
             r
         }
     }
+    //An inverse of r modulo the ideal, or None if r is not a unit modulo it, or it cannot tell.
+    fn inverse_modulo(_r: &R) -> Option<R> {
+        None
+    }
+    //Representatives of the coefficients of the product of polynomials with coefficients a and b
+    //modulo the ideal, for ideals that know a faster way than the quotient ring's own
+    //multiplication. None means use that.
+    fn polynomial_product(_a: &[R], _b: &[R]) -> Option<Vec<R>> {
+        None
+    }
 }
 
 impl<R, O, S, P> RingOperations<(R, S)> for (O, P)
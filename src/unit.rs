@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::{structure::{ring::{Ring, RingOperations}, group::Group, monoid::Monoid, euclidean_ring::EuclideanRing}, operation::O2, set::{Set, Subset}};
+use crate::{structure::{ring::{Ring, RingOperations}, group::Group, monoid::Monoid, euclidean_ring::EuclideanRing}, operation::O2, set::{Set, Subset}, polynomial::Polynomial, modular::euclidean_divides};
 
 pub struct Unit<R:Ring<O>,O:RingOperations<R>> {
     u: R,
@@ -40,9 +40,22 @@ impl<R:Ring<O>,O:RingOperations<R>> Group<O::TIMES> for Unit<R,O> {
 pub trait TryInverse<O:RingOperations<Self>>: Ring<O> {
     //This must return the multiplicative inverse of self, or None if no such inverse exists.
     fn try_inverse(self)->Option<Self>;
+    //Whether the polynomial g divides t, when the leading coefficient of g is not a unit, so that
+    //dividing by g leaves the ring. Without more structure only zero is known to be a multiple,
+    //and anything else panics.
+    fn polynomial_divides(_g:&Polynomial<Self,O>, t:&Polynomial<Self,O>) -> bool {
+        if t.coefficients.is_empty() {
+            return true;
+        }
+        panic!("divisibility by a polynomial whose leading coefficient is not a unit is undecided here")
+    }
 }
 impl<R:EuclideanRing<O>,O:RingOperations<R>> TryInverse<O> for R {
     fn try_inverse(self)->Option<Self> {
+        //Division by zero is not defined, and may panic.
+        if self==R::zero() {
+            return None;
+        }
         let (q,r)=R::one().divide(&self);
         if r==R::zero() {
             Some(q)
@@ -50,6 +63,11 @@ impl<R:EuclideanRing<O>,O:RingOperations<R>> TryInverse<O> for R {
             None
         }
     }
+    //By Gauss's lemma, g divides t exactly when it divides t over the fraction field, i.e. the
+    //pseudo-remainder vanishes, and the content of g divides the content of t.
+    fn polynomial_divides(g:&Polynomial<Self,O>, t:&Polynomial<Self,O>) -> bool {
+        t.prem(g)==Polynomial::zero() && euclidean_divides(&g.content(), &t.content())
+    }
 }
 impl<R:Ring<O>,O:RingOperations<R>> Subset<R> for Unit<R,O> where R:TryInverse<O> {
     fn contains(t: &R) -> bool {